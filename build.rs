@@ -0,0 +1,51 @@
+//! Generates the embedded asset pack.
+//!
+//! Every file within `assets/`, as well as the font, gets embedded into the executable via
+//! `include_bytes!`, keyed by its path relative to the crate root.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf}
+};
+
+const ROOTS: [&str; 2] = ["assets", "joystix.otf"];
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    let mut files = Vec::new();
+    for root in ROOTS {
+        println!("cargo:rerun-if-changed={root}");
+        collect(&manifest_dir, &manifest_dir.join(root), &mut files);
+    }
+    files.sort();
+
+    let mut out = String::from("pub static FILES: &[(&str, &[u8])] = &[\n");
+    for (key, path) in files {
+        writeln!(out, "    ({key:?}, include_bytes!({path:?})),").unwrap();
+    }
+    out.push_str("];\n");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("asset_pack.rs");
+    fs::write(out_path, out).unwrap();
+}
+
+/// Collects `(key, absolute path)` pairs for every file at or below `path`.
+fn collect(base: &Path, path: &Path, files: &mut Vec<(String, String)>) {
+    if path.is_dir() {
+        for entry in fs::read_dir(path).unwrap() {
+            collect(base, &entry.unwrap().path(), files);
+        }
+    } else if path.is_file() {
+        let key = path
+            .strip_prefix(base)
+            .unwrap()
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        files.push((key, path.to_string_lossy().into_owned()));
+    }
+}
@@ -0,0 +1,178 @@
+//! Achievements, unlocked by what happens during runs.
+//!
+//! Most achievements are checked against the [`RunStats`] of the current run, the rest react
+//! to [`GameEvent`]s. Unlock dates are kept in `achievements.txt` in [`paths::data_dir`], one
+//! `key = YYYY-MM-DD` pair per line.
+
+use std::{fmt::Write, fs, io, path::PathBuf};
+
+use crate::library::{GameEvent, RunStats, TextId, component::Class, paths, today};
+
+/// Every achievement there is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AchievementId {
+    /// Collect 10 cheese in a row without taking damage.
+    CleanPlate,
+    /// Survive until the difficulty reaches 300.
+    HotKitchen,
+    /// Dash through a laser.
+    LaserDancer,
+    /// Graze 25 hazards in one run.
+    CloseShave,
+    /// Reach the highest multiplier.
+    FullStack,
+    /// Survive for 5 minutes.
+    SlowCooker
+}
+
+impl AchievementId {
+    pub const COUNT: usize = 6;
+    pub const ALL: [AchievementId; Self::COUNT] = [
+        AchievementId::CleanPlate,
+        AchievementId::HotKitchen,
+        AchievementId::LaserDancer,
+        AchievementId::CloseShave,
+        AchievementId::FullStack,
+        AchievementId::SlowCooker
+    ];
+
+    /// Returns the key of the achievement in the save file.
+    pub fn key(self) -> &'static str {
+        match self {
+            AchievementId::CleanPlate => "clean_plate",
+            AchievementId::HotKitchen => "hot_kitchen",
+            AchievementId::LaserDancer => "laser_dancer",
+            AchievementId::CloseShave => "close_shave",
+            AchievementId::FullStack => "full_stack",
+            AchievementId::SlowCooker => "slow_cooker"
+        }
+    }
+    /// Returns the name and the description of the achievement.
+    pub fn text(self) -> (TextId, TextId) {
+        match self {
+            AchievementId::CleanPlate => (TextId::CleanPlate, TextId::CleanPlateDesc),
+            AchievementId::HotKitchen => (TextId::HotKitchen, TextId::HotKitchenDesc),
+            AchievementId::LaserDancer => (TextId::LaserDancer, TextId::LaserDancerDesc),
+            AchievementId::CloseShave => (TextId::CloseShave, TextId::CloseShaveDesc),
+            AchievementId::FullStack => (TextId::FullStack, TextId::FullStackDesc),
+            AchievementId::SlowCooker => (TextId::SlowCooker, TextId::SlowCookerDesc)
+        }
+    }
+    /// Returns whether the run so far earns the achievement.
+    fn earned_by(self, stats: &RunStats) -> bool {
+        match self {
+            AchievementId::CleanPlate => stats.best_clean_streak >= 10,
+            AchievementId::HotKitchen => stats.peak_difficulty >= 300.00,
+            AchievementId::CloseShave => stats.grazes >= 25,
+            AchievementId::FullStack => stats.peak_multiplier >= 4.00,
+            AchievementId::SlowCooker => stats.time >= 60.00 * 60.00 * 5.00,
+            AchievementId::LaserDancer => false
+        }
+    }
+    /// Returns whether the event earns the achievement.
+    fn earned_by_event(self, event: GameEvent) -> bool {
+        match (self, event) {
+            (AchievementId::LaserDancer, GameEvent::DashedThrough(class)) => {
+                matches!(class, Class::Laser)
+            }
+            _ => false
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Which achievements are unlocked, and when.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Achievements {
+    /// Unlock date of each achievement, as `YYYY-MM-DD`.
+    dates: [Option<String>; AchievementId::COUNT]
+}
+
+impl Achievements {
+    /// Loads the unlocked achievements, starting with none if there is no save file.
+    pub fn load() -> Achievements {
+        fs::read_to_string(Achievements::file())
+            .map(|text| Achievements::parse(&text))
+            .unwrap_or_default()
+    }
+    /// Writes the unlocked achievements, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let path = Achievements::file();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_text())
+    }
+    /// Returns the date the achievement was unlocked on, if it is.
+    pub fn unlocked(&self, id: AchievementId) -> Option<&str> {
+        self.dates[id.index()].as_deref()
+    }
+    /// Unlocks whatever the run's statistics and this frame's events earn, returning the
+    /// achievements that were newly unlocked.
+    pub fn check(&mut self, stats: &RunStats, events: &[GameEvent]) -> Vec<AchievementId> {
+        let earned = AchievementId::ALL.into_iter().filter(|&id| {
+            id.earned_by(stats) || events.iter().any(|&event| id.earned_by_event(event))
+        });
+        let new: Vec<_> = earned.filter(|&id| self.unlocked(id).is_none()).collect();
+        for &id in &new {
+            self.dates[id.index()] = Some(today());
+        }
+        new
+    }
+
+    fn file() -> PathBuf {
+        paths::data_dir().join("achievements.txt")
+    }
+
+    fn parse(text: &str) -> Achievements {
+        let mut achievements = Achievements::default();
+        let pairs = text
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()));
+        for (key, date) in pairs {
+            if let Some(id) = AchievementId::ALL.into_iter().find(|id| id.key() == key) {
+                achievements.dates[id.index()] = Some(date.to_string());
+            }
+        }
+        achievements
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = String::new();
+        for id in AchievementId::ALL {
+            if let Some(date) = self.unlocked(id) {
+                writeln!(text, "{} = {date}", id.key()).expect("string write cannot fail");
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_achievements_check_and_round_trip() {
+        let mut achievements = Achievements::default();
+        let stats = RunStats {
+            grazes: 30,
+            ..RunStats::default()
+        };
+        let events = [GameEvent::DashedThrough(Class::Laser)];
+        let new = achievements.check(&stats, &events);
+        assert_eq!(new, [AchievementId::LaserDancer, AchievementId::CloseShave]);
+        assert!(achievements.check(&stats, &events).is_empty());
+
+        let loaded = Achievements::parse(&achievements.to_text());
+        assert_eq!(loaded, achievements);
+    }
+}
@@ -1,6 +1,6 @@
 use macroquad::{color::Color, texture::Texture2D};
 
-use super::{sound, sprites};
+use super::{SoundId, SpriteId, Volumes, sound, sprites};
 
 /// Helps loading all assets into the game with some handy util functions
 ///
@@ -25,11 +25,12 @@ impl AssetLoader {
         }
     }
 
-    /// Loads a vector of sprite paths into this [`AssetLoader`],
+    /// Loads a vector of sprite IDs into this [`AssetLoader`],
     /// making the sprites loaded available for rendering the [`Texture2D`]s
     ///
-    /// This function also allows assigning a color for each sprite, facilitating particle
-    /// effects and such.
+    /// Each sprite gets a small palette of its most common colours, the first of which is
+    /// used as its color, facilitating particle effects and such. A color can also be
+    /// assigned by hand, overriding the extracted one.
     ///
     /// It returns a reference to itself to facilitate chain calls.
     ///
@@ -38,25 +39,23 @@ impl AssetLoader {
     /// let mut asset_loader = AssetLoader::new();
     ///
     /// asset_loader.load_sprites(vec![
-    ///   ("sprite_id", Color::RED), // Loads assets/sprites/sprite_id.png
-    ///   ("sprite_id2", Color::BLUE), // Loads assets/sprites/sprite_id2.png
+    ///   SpriteId::Cheese, // Loads assets/sprites/cheese.png
+    ///   (SpriteId::Heart, Color::BLUE), // Loads assets/sprites/heart.png
     /// ]);
     ///
-    /// asset_loader.texture("sprite_id"); // Returns a Texture2D
+    /// asset_loader.texture(SpriteId::Cheese); // Returns a Texture2D
     /// ```
-    pub async fn load_sprites(
-        &mut self,
-        sprite_paths: Vec<impl sprites::IntoPathColor>
-    ) -> &mut Self {
-        self.sprites.load_many(sprite_paths).await;
+    pub fn load_sprites(&mut self, sprite_ids: Vec<impl sprites::IntoIdColor>) -> &mut Self {
+        self.sprites.load_many(sprite_ids);
         self
     }
 
     /// Loads a vector of sound configurations into this [`AssetLoader`],
     /// making the sounds loaded available for playing.
     ///
-    /// Reference a sound by its ID, which is the name of the folder within assets/sound
-    /// that contains the sound variations. Sound loader picks up all sound files within.
+    /// Reference a sound by its [`SoundId`], which maps onto the name of the folder within
+    /// assets/sound that contains the sound variations. Sound loader picks up all sound files
+    /// within.
     ///
     /// **Supported file types: .wav, .ogg, .flac, .mp3**
     ///
@@ -75,14 +74,14 @@ impl AssetLoader {
     ///
     /// asset_loader.load_sounds(vec![
     ///    SoundConfig {
-    ///       id: "explosion"
+    ///       id: SoundId::Explosion,
     ///       volume: 1.0,
     ///       looped: false,
     ///    },
     /// ]);
     ///
     /// // Plays explosion1.wav or explosion2.wav randomly
-    /// asset_loader.play_sound("explosion");
+    /// asset_loader.play_sound(SoundId::Explosion);
     ///
     /// ```
     pub async fn load_sounds(&mut self, sound_configs: Vec<impl Into<sound::Config>>) -> &mut Self {
@@ -97,21 +96,26 @@ impl AssetLoader {
     /// ## Example
     /// ```
     /// // ... load sounds using AssetLoader::load_sounds() ...
-    /// asset_loader.play_sound("sound_id");
+    /// asset_loader.play_sound(SoundId::Dash);
     /// ```
-    pub fn play_sound(&self, id: &str) {
+    pub fn play_sound(&self, id: SoundId) {
         self.sounds.play(id);
     }
 
+    /// Scales the volume of every sound, see [`Volumes`].
+    pub fn set_volumes(&mut self, volumes: Volumes) {
+        self.sounds.set_volumes(volumes);
+    }
+
     /// Returns a reference to the [`Texture2D`] for the given sprite ID.
     ///
     /// ## Example
     /// ```
     /// // ... load sprites using AssetLoader::load_sprites() ...
-    /// let texture = asset_loader.texture("sprite_id");
+    /// let texture = asset_loader.texture(SpriteId::Burger);
     /// ```
-    pub fn texture(&self, path: &str) -> &Texture2D {
-        self.sprites.texture(path)
+    pub fn texture(&self, id: SpriteId) -> &Texture2D {
+        self.sprites.texture(id)
     }
 
     /// Returns a reference to the [Color] for the given sprite ID.
@@ -119,9 +123,20 @@ impl AssetLoader {
     /// ## Example
     /// ```
     /// // ... load sprites using AssetLoader::load_sprites() ...
-    /// let color = asset_loader.color("sprite_id");
+    /// let color = asset_loader.color(SpriteId::Cheese);
+    /// ```
+    pub fn color(&self, id: SpriteId) -> &Color {
+        self.sprites.color(id)
+    }
+
+    /// Returns the most common colors of the given sprite, most common first.
+    ///
+    /// ## Example
+    /// ```
+    /// // ... load sprites using AssetLoader::load_sprites() ...
+    /// let palette = asset_loader.palette(SpriteId::Cheese);
     /// ```
-    pub fn color(&self, path: &str) -> &Color {
-        self.sprites.color(path)
+    pub fn palette(&self, id: SpriteId) -> &[Color] {
+        self.sprites.palette(id)
     }
 }
@@ -0,0 +1,102 @@
+/// Every sprite the game knows about.
+///
+/// Each variant maps onto `assets/sprites/<name>.png`, see [`SpriteId::name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpriteId {
+    Burger,
+    BurgerInvuln,
+    Bullet,
+    Flak,
+    Slug,
+    FlakChild,
+    Cheese,
+    Heart
+}
+
+impl SpriteId {
+    pub const COUNT: usize = 8;
+    pub const ALL: [SpriteId; Self::COUNT] = [
+        SpriteId::Burger,
+        SpriteId::BurgerInvuln,
+        SpriteId::Bullet,
+        SpriteId::Flak,
+        SpriteId::Slug,
+        SpriteId::FlakChild,
+        SpriteId::Cheese,
+        SpriteId::Heart
+    ];
+
+    /// Returns the file name of the sprite, without the extension.
+    pub fn name(self) -> &'static str {
+        match self {
+            SpriteId::Burger => "burger",
+            SpriteId::BurgerInvuln => "burger_invuln",
+            SpriteId::Bullet => "bullet",
+            SpriteId::Flak => "flak",
+            SpriteId::Slug => "slug",
+            SpriteId::FlakChild => "flak_child",
+            SpriteId::Cheese => "cheese",
+            SpriteId::Heart => "heart"
+        }
+    }
+
+    pub(super) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Every sound the game knows about.
+///
+/// Each variant maps onto the folder `assets/sounds/<name>`, see [`SoundId::name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundId {
+    Explosion,
+    Heal,
+    Laser,
+    Damage,
+    Dash,
+    Music1
+}
+
+impl SoundId {
+    pub const COUNT: usize = 6;
+    pub const ALL: [SoundId; Self::COUNT] = [
+        SoundId::Explosion,
+        SoundId::Heal,
+        SoundId::Laser,
+        SoundId::Damage,
+        SoundId::Dash,
+        SoundId::Music1
+    ];
+
+    /// Returns the name of the folder holding the sound variations.
+    pub fn name(self) -> &'static str {
+        match self {
+            SoundId::Explosion => "explosion",
+            SoundId::Heal => "heal",
+            SoundId::Laser => "laser",
+            SoundId::Damage => "damage",
+            SoundId::Dash => "dash",
+            SoundId::Music1 => "music1"
+        }
+    }
+
+    pub(super) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_match_indices() {
+        for (i, id) in SpriteId::ALL.into_iter().enumerate() {
+            assert_eq!(id.index(), i);
+        }
+        for (i, id) in SoundId::ALL.into_iter().enumerate() {
+            assert_eq!(id.index(), i);
+        }
+    }
+}
@@ -1,4 +1,8 @@
 mod asset_loader;
+mod ids;
+pub mod pack;
 mod sound;
 pub mod sprites;
 pub use asset_loader::*;
+pub use ids::*;
+pub use sound::Volumes;
@@ -0,0 +1,72 @@
+//! The asset pack embedded into the executable by the build script.
+//!
+//! Files are looked up by their path relative to the crate root, e.g. `assets/sprites/burger.png`.
+//! Loose files on disk take priority over the embedded ones, so assets can be tweaked or
+//! replaced without recompiling. See [`paths::asset_candidates`] for where loose files are found.
+
+use std::{borrow::Cow, fs};
+
+use crate::library::paths;
+
+include!(concat!(env!("OUT_DIR"), "/asset_pack.rs"));
+
+/// Reads the file at the given path, preferring a loose file over the embedded one.
+pub fn read(path: &str) -> Option<Cow<'static, [u8]>> {
+    paths::asset_candidates(path)
+        .into_iter()
+        .find_map(|candidate| fs::read(candidate).ok())
+        .map(Cow::Owned)
+        .or_else(|| embedded(path).map(Cow::Borrowed))
+}
+
+/// Lists the names of the files directly within the given directory, sorted, including the
+/// loose files within it.
+pub fn list(dir: &str) -> Vec<String> {
+    let prefix = format!("{}/", dir.trim_end_matches('/'));
+    let mut names: Vec<String> = FILES
+        .iter()
+        .filter_map(|(key, _)| key.strip_prefix(&prefix))
+        .filter(|name| !name.contains('/'))
+        .map(str::to_string)
+        .collect();
+
+    let entries = paths::asset_candidates(dir)
+        .into_iter()
+        .filter_map(|candidate| fs::read_dir(candidate).ok())
+        .flatten();
+    names.extend(
+        entries
+            .filter_map(Result::ok)
+            .filter(|e| e.path().is_file())
+            .filter_map(|e| e.file_name().into_string().ok())
+    );
+
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+fn embedded(path: &str) -> Option<&'static [u8]> {
+    FILES
+        .binary_search_by(|(key, _)| (*key).cmp(path))
+        .ok()
+        .map(|i| FILES[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_is_sorted() {
+        assert!(FILES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_embedded() {
+        assert!(embedded("joystix.otf").is_some());
+        assert!(embedded("assets/sprites/burger.png").is_some());
+        assert!(embedded("assets/sprites/not_a_sprite.png").is_none());
+        assert!(list("assets/sounds/dash").contains(&"dash.wav".to_string()));
+    }
+}
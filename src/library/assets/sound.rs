@@ -1,76 +1,99 @@
-use std::{collections::HashMap, fs};
-
 use macroquad::{
-    audio::{PlaySoundParams, Sound, load_sound, play_sound},
+    audio::{PlaySoundParams, Sound, load_sound_from_bytes, play_sound, set_sound_volume},
     rand::ChooseRandom
 };
 
+use super::{SoundId, pack};
+
 #[derive(Debug)]
 pub struct Config {
     pub volume: f32,
     pub looped: bool,
-    pub id:     &'static str
+    pub id:     SoundId
 }
 
-impl From<&'static str> for Config {
-    fn from(value: &'static str) -> Self {
-        Config::from_name(value)
+impl From<SoundId> for Config {
+    fn from(value: SoundId) -> Self {
+        Config::from_id(value)
     }
 }
 
-impl From<(f32, bool, &'static str)> for Config {
-    fn from(value: (f32, bool, &'static str)) -> Self {
+impl From<(f32, bool, SoundId)> for Config {
+    fn from(value: (f32, bool, SoundId)) -> Self {
         let (volume, looped, id) = value;
         Config { volume, looped, id }
     }
 }
 
 impl Config {
-    pub fn from_name(name: &'static str) -> Self {
+    pub fn from_id(id: SoundId) -> Self {
         Self {
-            id: name,
-            ..Default::default()
+            volume: 0.05,
+            looped: false,
+            id
         }
     }
 }
 
-impl Default for Config {
+/// Volume multipliers applied on top of each sound's own volume. Looped sounds count as music,
+/// everything else as sound effects.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Volumes {
+    pub master: f32,
+    pub music:  f32,
+    pub sfx:    f32
+}
+
+impl Default for Volumes {
     fn default() -> Self {
         Self {
-            volume: 0.05,
-            looped: false,
-            id:     ""
+            master: 1.00,
+            music:  1.00,
+            sfx:    1.00
         }
     }
 }
 
+impl Volumes {
+    /// Returns the volume a sound with the given config plays at.
+    pub fn of(&self, config: &Config) -> f32 {
+        let channel = if config.looped { self.music } else { self.sfx };
+        config.volume * self.master * channel
+    }
+}
+
 #[derive(Debug)]
-pub struct Loader(HashMap<String, Config>, HashMap<String, Vec<Sound>>);
+pub struct Loader {
+    sounds:  Vec<Option<(Config, Vec<Sound>)>>,
+    volumes: Volumes
+}
 
 impl Loader {
     pub fn new() -> Self {
-        Loader(HashMap::new(), HashMap::new())
+        Loader {
+            sounds:  (0..SoundId::COUNT).map(|_| None).collect(),
+            volumes: Volumes::default()
+        }
     }
 
     pub async fn load_many<T: Into<Config>>(&mut self, sound_configs: Vec<T>) {
         for sc in sound_configs {
             let sound_config = sc.into();
-            let paths =
-                fs::read_dir(format!("assets/sounds/{}", sound_config.id)).unwrap_or_else(|_err| {
-                    panic!(
-                        "Invalid sound id argument! Path: assets/sounds/{}",
-                        sound_config.id
-                    )
-                });
+            let name = sound_config.id.name();
+            let paths = pack::list(&format!("assets/sounds/{name}"));
+            assert!(
+                !paths.is_empty(),
+                "Invalid sound id argument! Path: assets/sounds/{name}"
+            );
 
             // Find all sound variations and save them
             let mut sound_variations: Vec<Sound> = Vec::new();
 
-            for sound_path in paths {
-                let sound_variation = sound_path.unwrap().file_name().into_string().unwrap();
-
-                let full_path = format!("assets/sounds/{}/{}", sound_config.id, sound_variation);
-                let sound_result = load_sound(&full_path).await;
+            for sound_variation in paths {
+                let full_path = format!("assets/sounds/{name}/{sound_variation}");
+                let bytes = pack::read(&full_path)
+                    .unwrap_or_else(|| panic!("Invalid sound name argument! Path: {full_path}"));
+                let sound_result = load_sound_from_bytes(&bytes).await;
 
                 let sound = sound_result.unwrap_or_else(|_err| {
                     panic!("Invalid sound name argument! Path: {full_path}")
@@ -79,11 +102,21 @@ impl Loader {
                 sound_variations.push(sound);
             }
 
-            // Save sound variations
-            self.1.insert(sound_config.id.to_string(), sound_variations);
+            // Save sound config alongside its variations
+            let index = sound_config.id.index();
+            self.sounds[index] = Some((sound_config, sound_variations));
+        }
+    }
 
-            // Save sound config
-            self.0.insert(sound_config.id.to_string(), sound_config);
+    /// Changes the volume of every sound, including looped ones that are already playing.
+    pub fn set_volumes(&mut self, volumes: Volumes) {
+        self.volumes = volumes;
+        for (config, variations) in self.sounds.iter().flatten() {
+            if config.looped {
+                for sound in variations {
+                    set_sound_volume(sound, volumes.of(config));
+                }
+            }
         }
     }
 
@@ -93,13 +126,11 @@ impl Loader {
     /// ## Example
     /// ```
     /// // ... load sounds using SoundLoader::load_many() ...
-    /// sound_loader.play("sound_id");
-    pub fn play(&self, id: &str) {
-        let error_msg = format!("Invalid sound id '{id}' for playing.");
-
-        let sound_config = self.0.get(id).expect(&error_msg);
-
-        let sound_variations = self.1.get(id).expect(&error_msg);
+    /// sound_loader.play(SoundId::Dash);
+    pub fn play(&self, id: SoundId) {
+        let (sound_config, sound_variations) = self.sounds[id.index()]
+            .as_ref()
+            .unwrap_or_else(|| panic!("Sound '{}' was never loaded", id.name()));
 
         let sound = sound_variations
             .choose()
@@ -109,7 +140,7 @@ impl Loader {
             sound,
             PlaySoundParams {
                 looped: sound_config.looped,
-                volume: sound_config.volume
+                volume: self.volumes.of(sound_config)
             }
         );
     }
@@ -1,32 +1,40 @@
 use std::collections::HashMap;
 
+use image::RgbaImage;
 use macroquad::{
     color::Color,
-    prelude::{Texture2D, load_texture}
+    prelude::{ImageFormat, Texture2D}
 };
 
+use super::{SpriteId, pack};
+
+/// The number of colours kept in each sprite's palette.
+const PALETTE_SIZE: usize = 4;
+
 #[derive(Debug)]
 pub struct Sprite {
     pub color:   Color,
+    pub palette: Vec<Color>,
     pub texture: Texture2D
 }
 
 #[derive(Debug)]
-pub struct Loader(HashMap<String, Sprite>);
+pub struct Loader(Vec<Option<Sprite>>);
 
-pub trait IntoPathColor: Clone {
-    fn into_path_color(self) -> (String, Color);
+/// A sprite ID, optionally paired with a colour overriding the one extracted from the image.
+pub trait IntoIdColor: Clone {
+    fn into_id_color(self) -> (SpriteId, Option<Color>);
 }
 
-impl IntoPathColor for (&str, Color) {
-    fn into_path_color(self) -> (String, Color) {
-        (self.0.to_string(), self.1)
+impl IntoIdColor for (SpriteId, Color) {
+    fn into_id_color(self) -> (SpriteId, Option<Color>) {
+        (self.0, Some(self.1))
     }
 }
 
-impl IntoPathColor for &str {
-    fn into_path_color(self) -> (String, Color) {
-        (self.to_string(), Color::default())
+impl IntoIdColor for SpriteId {
+    fn into_id_color(self) -> (SpriteId, Option<Color>) {
+        (self, None)
     }
 }
 
@@ -38,41 +46,106 @@ impl Default for Loader {
 
 impl Loader {
     pub fn new() -> Self {
-        Loader(HashMap::new())
+        Loader((0..SpriteId::COUNT).map(|_| None).collect())
     }
+    /// Loads the given sprites. Sprites with a given colour use it as their only palette
+    /// colour, the others get the most common colours of their image instead.
+    ///
     /// # Panics
     ///
-    /// Panics if no file matches any of the given file names.
-    pub async fn load_many(&mut self, paths: Vec<impl IntoPathColor>) {
-        for pc in paths {
-            let (path, color) = pc.into_path_color();
-            let full_path = format!("assets/sprites/{path}.png");
+    /// Panics if no file matches any of the given sprite IDs, or if a file is not a valid PNG.
+    pub fn load_many(&mut self, ids: Vec<impl IntoIdColor>) {
+        for ic in ids {
+            let (id, color) = ic.into_id_color();
+            let full_path = format!("assets/sprites/{}.png", id.name());
 
-            let texture = load_texture(&full_path)
-                .await
-                .unwrap_or_else(|_err| panic!("Invalid sprite name argument! Path: {full_path}"));
+            let bytes = pack::read(&full_path)
+                .unwrap_or_else(|| panic!("Invalid sprite name argument! Path: {full_path}"));
+            let image = image::load_from_memory(&bytes)
+                .unwrap_or_else(|_err| panic!("Invalid PNG file! Path: {full_path}"))
+                .to_rgba8();
 
-            self.0.insert(path, Sprite { color, texture });
+            let palette = color.map_or_else(|| palette_of(&image), |color| vec![color]);
+            let color = palette.first().copied().unwrap_or_default();
+            let texture = Texture2D::from_file_with_format(&bytes, Some(ImageFormat::Png));
+
+            self.0[id.index()] = Some(Sprite {
+                color,
+                palette,
+                texture
+            });
         }
     }
     /// # Panics
     ///
-    /// Panics if the given path is invalid.
-    pub fn texture(&self, path: &str) -> &Texture2D {
-        &self
-            .0
-            .get(path)
-            .unwrap_or_else(|| panic!("Invalid path '{path}' for texture"))
-            .texture
+    /// Panics if the sprite has not been loaded.
+    pub fn texture(&self, id: SpriteId) -> &Texture2D {
+        &self.get(id).texture
+    }
+    /// # Panics
+    ///
+    /// Panics if the sprite has not been loaded.
+    pub fn color(&self, id: SpriteId) -> &Color {
+        &self.get(id).color
     }
+
+    /// Returns the colour the sprite was loaded with, or up to [`PALETTE_SIZE`] colours of its
+    /// image, most common first.
+    ///
     /// # Panics
     ///
-    /// Panics if the given path is invalid.
-    pub fn color(&self, path: &str) -> &Color {
-        &self
-            .0
-            .get(path)
-            .unwrap_or_else(|| panic!("Invalid path '{path}' for color"))
-            .color
+    /// Panics if the sprite has not been loaded.
+    pub fn palette(&self, id: SpriteId) -> &[Color] {
+        &self.get(id).palette
+    }
+
+    fn get(&self, id: SpriteId) -> &Sprite {
+        self.0[id.index()]
+            .as_ref()
+            .unwrap_or_else(|| panic!("Sprite '{}' was never loaded", id.name()))
+    }
+}
+
+/// Finds the most common colours of the image, ignoring fully transparent pixels.
+fn palette_of(image: &RgbaImage) -> Vec<Color> {
+    let mut counts: HashMap<[u8; 4], usize> = HashMap::new();
+    for pixel in image.pixels().filter(|p| p[3] > 0) {
+        *counts.entry(pixel.0).or_default() += 1;
+    }
+
+    let mut by_count: Vec<_> = counts.into_iter().collect();
+    // sort by the rgba value as well, so ties resolve the same way every time
+    by_count.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+
+    by_count
+        .into_iter()
+        .take(PALETTE_SIZE)
+        .map(|([r, g, b, a], _)| Color::from_rgba(r, g, b, a))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use image::Rgba;
+
+    use super::*;
+
+    #[test]
+    fn test_palette_of() {
+        let mut image = RgbaImage::new(4, 4);
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            *pixel = match (x, y) {
+                (0, _) => Rgba([0, 0, 0, 0]),
+                (1, _) => Rgba([10, 20, 30, 255]),
+                (2, 0) => Rgba([40, 50, 60, 255]),
+                _ => Rgba([70, 80, 90, 255])
+            };
+        }
+
+        let palette = palette_of(&image);
+        assert_eq!(palette.len(), 3);
+        assert_eq!(palette[0], Color::from_rgba(70, 80, 90, 255));
+        assert_eq!(palette[1], Color::from_rgba(10, 20, 30, 255));
+        assert_eq!(palette[2], Color::from_rgba(40, 50, 60, 255));
     }
 }
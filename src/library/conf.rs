@@ -1,20 +1,26 @@
 use crate::*;
 /// # Panics
 ///
-/// This function panics if it does not find all of the following files in the `assets/sprites` folder:
+/// This function panics if it does not find all of the following files in the `assets/sprites`
+/// folder of the asset pack:
 /// * `icon_s.png`
 /// * `icon_m.png`
 /// * `icon_l.png`
+///
+/// The window size and fullscreen come from the [`Settings`] file.
 pub fn window() -> Conf {
-    let small_icon = image::open(r"assets/sprites/icon_s.png").unwrap();
-    let medium_icon = image::open(r"assets/sprites/icon_m.png").unwrap();
-    let large_icon = image::open(r"assets/sprites/icon_l.png").unwrap();
+    let settings = Settings::load();
+    let scale = f64::from(settings.window_scale.max(1));
+    let small_icon = load_icon(r"assets/sprites/icon_s.png");
+    let medium_icon = load_icon(r"assets/sprites/icon_m.png");
+    let large_icon = load_icon(r"assets/sprites/icon_l.png");
     Conf {
         window_title: TITLE.to_string(),
-        window_width: (CENTER_X * 2.00 * SCALE) as i32,
-        window_height: (CENTER_Y * 2.00 * SCALE) as i32,
+        window_width: (CENTER_X * 2.00 * scale) as i32,
+        window_height: (CENTER_Y * 2.00 * scale) as i32,
+        fullscreen: settings.fullscreen,
         sample_count: 0,
-        window_resizable: false,
+        window_resizable: true,
         icon: Some(miniquad::conf::Icon {
             small:  small_icon.into_bytes().try_into().unwrap(),
             medium: medium_icon.into_bytes().try_into().unwrap(),
@@ -23,3 +29,8 @@ pub fn window() -> Conf {
         ..Default::default()
     }
 }
+
+fn load_icon(path: &str) -> image::DynamicImage {
+    let bytes = pack::read(path).unwrap_or_else(|| panic!("Missing icon! Path: {path}"));
+    image::load_from_memory(&bytes).unwrap()
+}
@@ -15,6 +15,27 @@ pub const SCORE_TEXT_PARAMS: TextParams = TextParams {
 pub const BG: Color = color_u8!(55, 55, 55, 255);
 pub const BG_ON_DAMAGE: Color = color_u8!(255, 55, 55, 255);
 
+/// Colours of the hazards, which the colour-blind palette swaps for ones that stay apart
+/// without relying on red and green.
+pub struct Hazards {
+    pub laser:   Color,
+    /// What damage particles fade into.
+    pub damage:  Color,
+    /// The two colours a warning alternates between.
+    pub warning: (Color, Color)
+}
+
+pub const HAZARDS: Hazards = Hazards {
+    laser:   color_u8!(255, 55, 55, 255),
+    damage:  BG_ON_DAMAGE,
+    warning: (color_u8!(255, 55, 55, 255), color_u8!(255, 255, 55, 255))
+};
+pub const HAZARDS_COLORBLIND: Hazards = Hazards {
+    laser:   color_u8!(240, 228, 66, 255),
+    damage:  color_u8!(0, 114, 178, 255),
+    warning: (color_u8!(230, 159, 0, 255), color_u8!(86, 180, 233, 255))
+};
+
 // transform
 pub const CENTER_X: f64 = 80.00;
 pub const CENTER_Y: f64 = 60.00;
@@ -24,4 +45,5 @@ pub const SCREEN_X: f64 = CENTER_X * 2.00;
 pub const SCREEN_Y: f64 = CENTER_Y * 2.00;
 pub const SCREEN: Vector2 = Vector2(SCREEN_X, SCREEN_Y);
 
-pub const SCALE: f64 = 8.00;
+/// Default size of a game pixel on screen, in window pixels.
+pub const SCALE: u32 = 8;
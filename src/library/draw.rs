@@ -17,23 +17,63 @@ pub fn rec_top_left(pos: Vector2, w: i32, h: i32, color: Color) {
     draw_rectangle(pos.x() as f32, pos.y() as f32, w as f32, h as f32, color);
 }
 
+/// Blends from `a` at `t = 0` into `b` at `t = 1`.
+pub fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    Color::new(
+        lerp(a.r, b.r),
+        lerp(a.g, b.g),
+        lerp(a.b, b.b),
+        lerp(a.a, b.a)
+    )
+}
+
+/// Darkens the edges of the screen towards the given colour, fading out inwards.
+pub fn vignette(color: Color, strength: f32) {
+    const DEPTH: i32 = 6;
+    let (w, h) = (SCREEN_X as i32, SCREEN_Y as i32);
+    for i in 0..DEPTH {
+        let alpha = strength * (1.00 - i as f32 / DEPTH as f32);
+        let clr = Color { a: alpha, ..color };
+        let inset = f64::from(i);
+        let (inner_w, inner_h) = (w - i * 2, h - i * 2);
+        rec_top_left(Vector2(inset, inset), inner_w, 1, clr);
+        rec_top_left(Vector2(inset, SCREEN_Y - inset - 1.00), inner_w, 1, clr);
+        rec_top_left(Vector2(inset, inset + 1.00), 1, inner_h - 2, clr);
+        rec_top_left(
+            Vector2(SCREEN_X - inset - 1.00, inset + 1.00),
+            1,
+            inner_h - 2,
+            clr
+        );
+    }
+}
+
 pub fn copy_texture(texture: &Texture2D, pos: Vector2) {
+    copy_tinted(texture, pos, WHITE);
+}
+
+pub fn copy_tinted(texture: &Texture2D, pos: Vector2, tint: Color) {
     texture.set_filter(FilterMode::Nearest);
     draw_texture(
         texture,
         pos.x() as f32 - texture.width() * 0.50,
         pos.y() as f32 - texture.height() * 0.50,
-        WHITE
+        tint
     );
 }
 
 pub fn copy_with_rotation(texture: &Texture2D, pos: Vector2, rotation: f64) {
+    copy_tinted_with_rotation(texture, pos, rotation, WHITE);
+}
+
+pub fn copy_tinted_with_rotation(texture: &Texture2D, pos: Vector2, rotation: f64, tint: Color) {
     texture.set_filter(FilterMode::Nearest);
     draw_texture_ex(
         texture,
         pos.x() as f32 - texture.width() * 0.50,
         pos.y() as f32 - texture.height() * 0.50,
-        WHITE,
+        tint,
         DrawTextureParams {
             dest_size: None,
             rotation: rotation as f32,
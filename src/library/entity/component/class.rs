@@ -11,3 +11,19 @@ pub enum Class {
     FlakChild,
     Warning { dir: Vector2, delay: f64 }
 }
+
+impl Class {
+    /// Returns the name of the class, as used in exported statistics.
+    pub fn name(self) -> &'static str {
+        match self {
+            Class::None => "none",
+            Class::Bullet => "bullet",
+            Class::Slug => "slug",
+            Class::Laser => "laser",
+            Class::HealthPack => "health_pack",
+            Class::Flak => "flak",
+            Class::FlakChild => "flak_child",
+            Class::Warning { .. } => "warning"
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use std::f64::consts::TAU;
+
+use crate::*;
+
+/// Where the particles of an [`Emitter`] spawn and which way they fly.
+///
+/// Directional shapes are oriented along the direction given to [`Emitter::emit`].
+#[derive(Clone, Copy)]
+pub enum Shape {
+    /// Evenly spread out in every direction from a single point.
+    Point,
+    /// Evenly spread out in every direction from a circle around the center.
+    Ring { radius: f64 },
+    /// Randomly spread within `spread` radians of the direction.
+    Cone { spread: f64 },
+    /// Spawned along a line perpendicular to the direction, flying along it.
+    Line { length: f64 }
+}
+
+/// The colors given to the particles of an [`Emitter`].
+#[derive(Clone, Copy)]
+pub enum Colors {
+    /// Blends from the first color into the second over the lifespan of the particle.
+    Gradient(Color, Color),
+    /// Each particle picks a color from the palette of the sprite, cycling through it.
+    Palette(SpriteId)
+}
+
+/// Describes a burst of particles. See [`ParticleEffect::emitter`] for the emitters used in game.
+#[derive(Clone, Copy)]
+pub struct Emitter {
+    pub shape:    Shape,
+    pub count:    usize,
+    pub speed:    (f64, f64),
+    pub lifespan: (f64, f64),
+    pub size:     i32,
+    pub friction: f64,
+    pub gravity:  Vector2,
+    pub colors:   Colors,
+    pub fade:     bool
+}
+
+impl Emitter {
+    /// Emits a burst of particles at the given position, oriented along `dir`.
+    pub fn emit(
+        &self,
+        pos: Vector2,
+        dir: Vector2,
+        assets: &AssetLoader,
+        particles: &mut Vec<Particle>
+    ) {
+        let count = self.count as f64;
+        let base_angle = dir.angle();
+        let random_offset = rand(TAU);
+        particles.reserve(self.count);
+
+        for i in 0..self.count {
+            let even_angle = random_offset + TAU * (i as f64 / count);
+            let (spawn, angle) = match self.shape {
+                Shape::Point => (pos, even_angle),
+                Shape::Ring { radius } => (pos + Vector2::from(even_angle) * radius, even_angle),
+                Shape::Cone { spread } => (pos, base_angle + rand_between(-spread, spread)),
+                Shape::Line { length } => {
+                    let across = Vector2::from(base_angle).rotate_once();
+                    let along = (i as f64 + 0.50) / count - 0.50;
+                    (pos + across * (along * length), base_angle)
+                }
+            };
+            let speed = rand_between(self.speed.0, self.speed.1);
+            let (color, end_color) = match self.colors {
+                Colors::Gradient(start, end) => (start, end),
+                Colors::Palette(sprite) => {
+                    let palette = assets.palette(sprite);
+                    let color = palette
+                        .get(i % palette.len().max(1))
+                        .copied()
+                        .unwrap_or_else(|| *assets.color(sprite));
+                    (color, color)
+                }
+            };
+
+            particles.push(Particle {
+                size: self.size,
+                end_color,
+                fade: self.fade,
+                ..Particle::new(
+                    spawn,
+                    Vector2::from(angle) * speed,
+                    self.gravity,
+                    self.friction,
+                    rand_between(self.lifespan.0, self.lifespan.1),
+                    color
+                )
+            });
+        }
+    }
+}
+
+/// Every particle effect in the game.
+#[derive(Clone, Copy)]
+pub enum ParticleEffect {
+    CheesePickup,
+    Heal,
+    Damage,
+    FlakBurst,
+    LaserImpact,
+    Graze
+}
+
+const BASE: Emitter = Emitter {
+    shape:    Shape::Point,
+    count:    6,
+    speed:    (3.60, 4.40),
+    lifespan: (0.00, 20.00),
+    size:     2,
+    friction: 0.20,
+    gravity:  Vector2::ZERO,
+    colors:   Colors::Gradient(WHITE, WHITE),
+    fade:     false
+};
+
+impl ParticleEffect {
+    /// Returns the emitter describing this effect, drawn in the given hazard colours.
+    pub fn emitter(self, hazards: &Hazards) -> Emitter {
+        use ParticleEffect as P;
+        match self {
+            P::CheesePickup => Emitter {
+                count: 5,
+                friction: 0.33,
+                colors: Colors::Palette(SpriteId::Cheese),
+                ..BASE
+            },
+            P::Heal => Emitter {
+                colors: Colors::Palette(SpriteId::Heart),
+                ..BASE
+            },
+            P::Damage => Emitter {
+                shape: Shape::Ring { radius: 4.00 },
+                count: 10,
+                speed: (1.50, 3.00),
+                lifespan: (10.00, 25.00),
+                friction: 0.10,
+                gravity: Vector2(0.00, 0.15),
+                colors: Colors::Gradient(WHITE, hazards.damage),
+                fade: true,
+                ..BASE
+            },
+            P::FlakBurst => Emitter {
+                count: 8,
+                speed: (1.00, 2.00),
+                lifespan: (8.00, 16.00),
+                friction: 0.15,
+                colors: Colors::Palette(SpriteId::Flak),
+                fade: true,
+                ..BASE
+            },
+            P::LaserImpact => Emitter {
+                shape: Shape::Cone { spread: 0.60 },
+                count: 8,
+                speed: (2.00, 5.00),
+                lifespan: (6.00, 14.00),
+                colors: Colors::Gradient(YELLOW, hazards.damage),
+                fade: true,
+                ..BASE
+            },
+            P::Graze => Emitter {
+                shape: Shape::Cone { spread: 0.40 },
+                count: 3,
+                speed: (1.50, 3.00),
+                lifespan: (4.00, 10.00),
+                colors: Colors::Gradient(WHITE, YELLOW),
+                fade: true,
+                ..BASE
+            }
+        }
+    }
+
+    /// Emits this effect. See [`Emitter::emit`].
+    pub fn emit(
+        self,
+        pos: Vector2,
+        dir: Vector2,
+        assets: &AssetLoader,
+        hazards: &Hazards,
+        particles: &mut Vec<Particle>
+    ) {
+        self.emitter(hazards).emit(pos, dir, assets, particles);
+    }
+}
@@ -4,6 +4,10 @@ pub mod consumable;
 pub use consumable::*;
 pub mod particle;
 pub use particle::*;
+pub mod emitter;
+pub use emitter::*;
+pub mod trail;
+pub use trail::*;
 
 pub mod component;
 pub mod sys;
@@ -11,25 +15,47 @@ pub mod sys;
 use crate::library::Vector2;
 
 pub struct Entity {
-    pub class:    component::Class,
-    pub pos:      Vector2,
-    pub vel:      Vector2,
-    pub acc:      Vector2,
-    pub age:      f64,
-    pub lifespan: f64,
-    pub alive:    bool
+    pub class:          component::Class,
+    pub pos:            Vector2,
+    pub vel:            Vector2,
+    pub acc:            Vector2,
+    pub age:            f64,
+    pub lifespan:       f64,
+    pub alive:          bool,
+    pub trail:          Option<Trail>,
+    pub graze:          Graze,
+    /// Smallest gap to a burger's hit range so far, in pixels. Recorded as a close call
+    /// once the entity has missed, see `sys::graze`.
+    pub closest:        Option<f64>,
+    /// Whether a dashing burger has passed through the entity.
+    pub dashed_through: bool
+}
+
+/// How close an entity has come to a burger without hitting it, see `sys::graze`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Graze {
+    #[default]
+    Clear,
+    /// Within the graze ring of the burger with the given index.
+    Near(usize),
+    /// Already grazed a burger, so it can't again.
+    Done
 }
 
 impl Default for Entity {
     fn default() -> Self {
         Self {
-            class:    component::Class::None,
-            pos:      Vector2::ZERO,
-            vel:      Vector2::ZERO,
-            acc:      Vector2::ZERO,
-            age:      0.00,
-            lifespan: 0.00,
-            alive:    true
+            class:          component::Class::None,
+            pos:            Vector2::ZERO,
+            vel:            Vector2::ZERO,
+            acc:            Vector2::ZERO,
+            age:            0.00,
+            lifespan:       0.00,
+            alive:          true,
+            trail:          None,
+            graze:          Graze::Clear,
+            closest:        None,
+            dashed_through: false
         }
     }
 }
@@ -1,14 +1,15 @@
-use std::f64::consts::TAU;
-
 use crate::*;
 pub struct Particle {
-    pub pos:      Vector2,
-    pub vel:      Vector2,
-    pub acc:      Vector2,
-    pub age:      f64,
-    pub lifespan: f64,
-    pub friction: f64,
-    pub color:    Color
+    pub pos:       Vector2,
+    pub vel:       Vector2,
+    pub acc:       Vector2,
+    pub age:       f64,
+    pub lifespan:  f64,
+    pub friction:  f64,
+    pub size:      i32,
+    pub color:     Color,
+    pub end_color: Color,
+    pub fade:      bool
 }
 
 impl Particle {
@@ -27,28 +28,54 @@ impl Particle {
             age: 0.00,
             lifespan,
             friction: fric,
+            size: 2,
+            color,
+            end_color: color,
+            fade: false
+        }
+    }
+    /// Returns the color of the particle at its current age, blending from `color` towards
+    /// `end_color` and fading out if `fade` is set.
+    pub fn current_color(&self) -> Color {
+        let t = if self.lifespan > 0.00 {
+            (self.age / self.lifespan).clamp(0.00, 1.00) as f32
+        } else {
+            1.00
+        };
+        let color = lerp_color(self.color, self.end_color, t);
+        if self.fade {
+            Color {
+                a: color.a * (1.00 - t),
+                ..color
+            }
+        } else {
             color
         }
     }
-    /// TODO: find a better way to do this
-    #[allow(clippy::too_many_arguments)]
-    pub fn from_center(number: usize, pos: Vector2, fric: f64, color: Color) -> Vec<Particle> {
-        let mut output = Vec::with_capacity(number);
-        let offset = rand(1.00);
-        let vel = 4.00;
-        let random_offset = rand(TAU);
-        for i in 0..number {
-            let angle = random_offset + TAU * (offset + i as f64 / (number as f64));
-            let vel = Vector2::from(angle) * (vel * 0.90 + rand(vel * 0.20));
-            output.push(Particle::new(
-                pos,
-                vel,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_color() {
+        let mut p = Particle {
+            end_color: Color::new(0.00, 1.00, 0.00, 1.00),
+            fade: true,
+            ..Particle::new(
                 Vector2::ZERO,
-                fric,
-                rand(20.),
-                color
-            ));
-        }
-        output
+                Vector2::ZERO,
+                Vector2::ZERO,
+                0.00,
+                10.00,
+                Color::new(1.00, 0.00, 0.00, 1.00)
+            )
+        };
+        assert_eq!(p.current_color(), Color::new(1.00, 0.00, 0.00, 1.00));
+        p.age = 5.00;
+        assert_eq!(p.current_color(), Color::new(0.50, 0.50, 0.00, 0.50));
+        p.age = 10.00;
+        assert!(p.current_color().a.abs() < f32::EPSILON);
     }
 }
@@ -5,19 +5,30 @@ pub struct Player {
     pub vel:         Vector2,
     pub acc:         Vector2,
     pub hp:          i32,
+    pub max_hp:      i32,
     pub invuln:      f64,
-    pub dash_charge: f64
+    pub dash_charge: f64,
+    /// Ticks left during which a dash press is still remembered.
+    pub dash_buffer: f64,
+    /// The last direction the player moved in, for dashing without holding a direction.
+    pub last_dir:    Vector2,
+    pub trail:       Trail
 }
 
 impl Player {
-    pub fn new(pos: Vector2) -> Self {
+    /// Creates a burger at full health.
+    pub fn new(pos: Vector2, max_hp: i32) -> Self {
         Self {
             pos,
             vel: Vector2::ZERO,
             acc: Vector2::ZERO,
-            hp: 8,
+            hp: max_hp,
+            max_hp,
             invuln: 0.00,
-            dash_charge: 1.00
+            dash_charge: 1.00,
+            dash_buffer: 0.00,
+            last_dir: Vector2(0.00, -1.00),
+            trail: Trail::new(5, 3.00)
         }
     }
 }
@@ -26,13 +37,11 @@ impl Player {
     pub fn is_alive(&self) -> bool {
         self.hp > 0
     }
-    pub fn max_hp() -> i32 {
-        8
-    }
-    pub fn dash(&mut self, input: &Input, asset_loader: &AssetLoader) {
-        asset_loader.play_sound("dash");
+    pub fn dash(&mut self, dir: Vector2, asset_loader: &AssetLoader) {
+        asset_loader.play_sound(SoundId::Dash);
         let charge_used = self.dash_charge;
-        self.vel += input.dir().normal() * charge_used * 7.00;
+        self.vel += dir.normal() * charge_used * 7.00;
+        self.dash_buffer = 0.00;
         self.invuln = charge_used * 15.00;
         self.dash_charge -= charge_used;
     }
@@ -43,6 +52,6 @@ impl Player {
         self.invuln <= 0.00
     }
     pub fn missing_hp(&self) -> i32 {
-        Self::max_hp() - self.hp
+        self.max_hp - self.hp
     }
 }
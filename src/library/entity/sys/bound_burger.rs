@@ -5,13 +5,15 @@ use crate::{
 
 pub fn run(state: &mut State) {
     let bounds = CENTER * 2.00;
-    let Vector2(x, y) = state.burger.pos;
-    if x < 0.00 || x > bounds.0 {
-        state.burger.vel.0 *= -1.00;
+    for burger in &mut state.burgers {
+        let Vector2(x, y) = burger.pos;
+        if x < 0.00 || x > bounds.0 {
+            burger.vel.0 *= -1.00;
+        }
+        if y < 0.00 || y > bounds.1 {
+            burger.vel.1 *= -1.00;
+        }
+        burger.pos.0 = burger.pos.0.max(0.00).min(bounds.0);
+        burger.pos.1 = burger.pos.1.max(0.00).min(bounds.1);
     }
-    if y < 0.00 || y > bounds.1 {
-        state.burger.vel.1 *= -1.00;
-    }
-    state.burger.pos.0 = state.burger.pos.0.max(0.00).min(bounds.0);
-    state.burger.pos.1 = state.burger.pos.1.max(0.00).min(bounds.1);
 }
@@ -1,5 +1,7 @@
-use crate::{State, library::Player};
+use crate::State;
 
 pub fn run(state: &mut State) {
-    state.burger.hp = std::cmp::min(state.burger.hp, Player::max_hp());
+    for burger in &mut state.burgers {
+        burger.hp = std::cmp::min(burger.hp, burger.max_hp);
+    }
 }
@@ -1,23 +1,33 @@
 use crate::{
     State,
-    library::{AssetLoader, Particle, cheese}
+    library::{AssetLoader, Hazards, ParticleEffect, SoundId, Vector2, cheese}
 };
 
 const BURGER_SIZE: f64 = 2.00;
 
-pub fn run(state: &mut State, assets: &AssetLoader) {
-    let cheese_dist = (state.cheese.pos - state.burger.pos).len();
-    if cheese_dist < (BURGER_SIZE + 7.00) {
-        state.score += 100;
-        assets.play_sound("heal"); // TODO: cheese sound
-        state.particles.extend(Particle::from_center(
-            5,
+pub fn run(state: &mut State, assets: &AssetLoader, hazards: &Hazards) {
+    let cheese_pos = state.cheese.pos;
+    let collected = state
+        .burgers
+        .iter()
+        .filter(|b| b.is_alive())
+        .any(|b| (cheese_pos - b.pos).len() < (BURGER_SIZE + 7.00));
+    if collected {
+        state.score += state.combo.collect();
+        state.stats.track_cheese();
+        state
+            .stats
+            .track_combo(state.combo.count, state.combo.multiplier());
+        assets.play_sound(SoundId::Heal); // TODO: cheese sound
+        ParticleEffect::CheesePickup.emit(
             state.cheese.pos,
-            0.33,
-            *assets.color("cheese")
-        ));
+            Vector2::ZERO,
+            assets,
+            hazards,
+            &mut state.particles
+        );
 
-        let new_pos = cheese::create_next_pos(state.cheese.next_pos);
+        let new_pos = cheese::create_next_pos(&state.rng, state.cheese.next_pos);
         state.cheese.pos = state.cheese.next_pos;
         state.cheese.next_pos = new_pos;
     }
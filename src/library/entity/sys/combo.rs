@@ -0,0 +1,5 @@
+use crate::State;
+
+pub fn run(state: &mut State, dt: f64) {
+    state.combo.update(dt);
+}
@@ -1,14 +1,45 @@
 use crate::{
     State,
-    library::{AssetLoader, Input}
+    library::{AssetLoader, Input, Settings, Vector2}
 };
 
-pub fn run(state: &mut State, dt: f64, input: &Input, assets: &AssetLoader) {
-    let burger = &mut state.burger;
-    burger.vel = input.dir().normal() * (0.55) * dt + burger.vel * 0.675f64.powf(dt);
-    burger.invuln = (burger.invuln - dt).max(0.00);
-    burger.dash_charge = (burger.dash_charge + 0.01 * dt).min(1.00);
-    if input.space.is_pressed() && burger.can_dash() && input.dir().len() > 0.00 {
-        burger.dash(input, assets);
+/// Moves every living burger according to its input. The n-th input belongs to the n-th burger.
+pub fn run(
+    state: &mut State,
+    dt: f64,
+    inputs: &[Input],
+    assets: &AssetLoader,
+    settings: &Settings
+) {
+    let recharge = state.preset.tuning().dash_recharge;
+    for (burger, input) in state.burgers.iter_mut().zip(inputs) {
+        if !burger.is_alive() {
+            continue;
+        }
+        burger.vel = input.dir() * (0.55) * dt + burger.vel * 0.675f64.powf(dt);
+        burger.invuln = (burger.invuln - dt).max(0.00);
+        burger.dash_charge = (burger.dash_charge + recharge * dt).min(1.00);
+
+        if input.dir().len() > 0.00 {
+            burger.last_dir = input.dir();
+        }
+        // remember dash presses for a little while, so pressing slightly early still dashes
+        if input.dash.is_pressed() {
+            burger.dash_buffer = settings.dash_buffer;
+        } else {
+            burger.dash_buffer = (burger.dash_buffer - dt).max(0.00);
+        }
+
+        let dir = if input.dir().len() > 0.00 {
+            input.dir()
+        } else if settings.dash_last_dir {
+            burger.last_dir
+        } else {
+            Vector2::ZERO
+        };
+        if burger.dash_buffer > 0.00 && burger.can_dash() && dir.len() > 0.00 {
+            burger.dash(dir, assets);
+            state.stats.dashes += 1;
+        }
     }
 }
@@ -1,17 +1,23 @@
 use crate::{
     State,
     library::{
-        AssetLoader, Entity, Radians, Vector2,
+        AssetLoader, Entity, Hazards, ParticleEffect, Radians, SoundId, Trail, Vector2,
         component::{self, Class}
     }
 };
 
-pub fn run(state: &mut State, assets: &AssetLoader) {
+pub fn run(state: &mut State, assets: &AssetLoader, hazards: &Hazards) {
     let mut flak = Vec::new();
     let mut lasers = Vec::new();
 
     state.entities.retain(|e| {
         if e.age >= e.lifespan {
+            // it expired without hitting
+            if e.alive
+                && let Some(closest) = e.closest
+            {
+                state.stats.track_miss(closest);
+            }
             match e.class {
                 Class::Flak => flak.push(e.pos),
                 Class::Warning { dir, delay: _ } => lasers.push((e.pos, dir)),
@@ -26,6 +32,8 @@ pub fn run(state: &mut State, assets: &AssetLoader) {
 
     let num = 8;
     for pos in flak {
+        let particles = &mut state.particles;
+        ParticleEffect::FlakBurst.emit(pos, Vector2::ZERO, assets, hazards, particles);
         for i in 0..8 {
             let dir = f64::from(i).as_radians() / f64::from(num);
             state.entities.push(Entity {
@@ -39,12 +47,13 @@ pub fn run(state: &mut State, assets: &AssetLoader) {
     }
 
     for (pos, dir) in lasers {
-        assets.play_sound("laser");
+        assets.play_sound(SoundId::Laser);
         state.entities.push(Entity {
             class: component::Class::Laser,
             pos: pos - dir * 40.00,
             vel: dir * 7.00,
             lifespan: 500.,
+            trail: Trail::for_class(Class::Laser),
             ..Default::default()
         });
     }
@@ -1,5 +1,23 @@
 use crate::State;
 
+/// Ramps up the difficulty, or lets the director adapt it if there is one. Practice runs keep
+/// their difficulty.
 pub fn run(state: &mut State, dt: f64) {
-    state.difficulty += 0.10 * dt;
+    if state.practice.is_some() {
+        return;
+    }
+    let ramp = state.preset.tuning().ramp;
+    let Some(director) = &mut state.director else {
+        state.difficulty += ramp * dt;
+        return;
+    };
+    let health = state
+        .burgers
+        .iter()
+        .filter(|b| b.is_alive())
+        .map(|b| f64::from(b.hp) / f64::from(b.max_hp))
+        .reduce(f64::min)
+        .unwrap_or(0.00);
+    director.update(dt, ramp, &state.stats, health);
+    state.difficulty = director.difficulty();
 }
@@ -0,0 +1,50 @@
+//! Rewards hazards passing close by without hitting.
+//!
+//! A hazard grazes a burger once it has been within a ring just outside its hit range and
+//! leaves it again, still alive. Each entity grazes at most once.
+//!
+//! The closest a hazard came is recorded as a close call once it has certainly missed: when it
+//! grazes, or when it expires without hitting (see `sys::destroy_old`).
+
+use super::player_collide::{BURGER_SIZE, effect};
+use crate::{
+    State,
+    library::{AssetLoader, Graze, Hazards, ParticleEffect, graze_points}
+};
+
+/// Width of the ring outside the hit range, in pixels.
+const GRAZE_RING: f64 = 6.00;
+/// Dash charge refilled per graze.
+const DASH_REFILL: f64 = 0.15;
+
+pub fn run(state: &mut State, assets: &AssetLoader, hazards: &Hazards) {
+    for (i, burger) in state.burgers.iter_mut().enumerate() {
+        if !burger.is_alive() || !burger.is_targetable() {
+            continue;
+        }
+        for e in state.entities.iter_mut().filter(|e| e.alive) {
+            let Some(effect) = effect::of(e.class).filter(|effect| effect.dmg > 0) else {
+                continue;
+            };
+            // whatever is still alive here missed, so far
+            let gap = (e.pos - burger.pos).len() - BURGER_SIZE - effect.range;
+            let closest = e.closest.map_or(gap, |closest| closest.min(gap));
+            e.closest = Some(closest);
+            let near = gap < GRAZE_RING;
+            match e.graze {
+                Graze::Clear if near => e.graze = Graze::Near(i),
+                Graze::Near(j) if j == i && !near => {
+                    e.graze = Graze::Done;
+                    state.stats.track_miss(closest);
+                    state.score += graze_points(state.combo.multiplier());
+                    state.stats.grazes += 1;
+                    burger.dash_charge = (burger.dash_charge + DASH_REFILL).min(1.00);
+                    let away = e.pos - burger.pos;
+                    let particles = &mut state.particles;
+                    ParticleEffect::Graze.emit(burger.pos, away, assets, hazards, particles);
+                }
+                _ => ()
+            }
+        }
+    }
+}
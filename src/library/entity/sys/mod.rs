@@ -2,11 +2,15 @@ pub mod age;
 pub mod bound_burger;
 pub mod bound_stats;
 pub mod cheese;
+pub mod combo;
 pub mod dash;
 pub mod destroy_dead;
 pub mod destroy_old;
 pub mod difficulty_up;
 pub mod friction;
+pub mod graze;
 pub mod player_collide;
 pub mod pos;
 pub mod spawn;
+pub mod stats;
+pub mod trail;
@@ -1,61 +1,96 @@
 use crate::{
     State,
-    library::{AssetLoader, Particle, Vector2}
+    library::{AssetLoader, GameEvent, Hazards, ParticleEffect, SoundId}
 };
 
-const BURGER_SIZE: f64 = 2.00;
+pub(super) const BURGER_SIZE: f64 = 2.00;
 
-pub fn run(state: &mut State, assets: &AssetLoader) {
-    if state.burger.is_targetable() {
+pub fn run(state: &mut State, assets: &AssetLoader, hazards: &Hazards) {
+    // invincible burgers still get hit, but keep their health and the game keeps going
+    let invincible = state.practice.is_some_and(|practice| practice.invincible);
+    for burger in &mut state.burgers {
+        if !burger.is_alive() {
+            continue;
+        }
+        if !burger.is_targetable() {
+            // dashing through hazards, counted once per hazard
+            for e in state
+                .entities
+                .iter_mut()
+                .filter(|e| e.alive && !e.dashed_through)
+            {
+                let passed = effect::of(e.class).is_some_and(|effect| {
+                    effect.dmg > 0 && (e.pos - burger.pos).len() < BURGER_SIZE + effect.range
+                });
+                if passed {
+                    e.dashed_through = true;
+                    state.events.push(GameEvent::DashedThrough(e.class));
+                }
+            }
+            continue;
+        }
         let mut dmg = 0;
-        for e in &mut state.entities {
+        // entities that already hit another burger are dead, and can't hit this one
+        for e in state.entities.iter_mut().filter(|e| e.alive) {
             let Some(effect) = effect::of(e.class) else {
                 continue;
             };
 
-            let center_dist = (e.pos - state.burger.pos).len();
+            let center_dist = (e.pos - burger.pos).len();
 
             if center_dist < (BURGER_SIZE + effect.range) {
                 dmg += effect.dmg;
                 e.alive = false;
+                if effect.dmg > 0 {
+                    state.stats.track_damage(e.class.name(), effect.dmg);
+                } else {
+                    state.stats.heals += 1;
+                }
                 if let Some(snd) = effect.sound {
                     assets.play_sound(snd);
                 }
-                if effect.make_particles {
-                    make_particles(e.pos, &mut state.particles, assets);
+                if let Some(particles) = effect.particles {
+                    particles.emit(e.pos, e.vel.negate(), assets, hazards, &mut state.particles);
                 }
             }
         }
         if dmg > 0 {
-            assets.play_sound("damage");
-            state.frozen_time += u32::try_from(dmg).expect("dmg > 0 has been checked") * 400;
+            assets.play_sound(SoundId::Damage);
+            ParticleEffect::Damage.emit(
+                burger.pos,
+                burger.vel,
+                assets,
+                hazards,
+                &mut state.particles
+            );
+            if !invincible {
+                state.frozen_time += u32::try_from(dmg).expect("dmg > 0 has been checked") * 400;
+                state.combo.reset();
+            }
+        }
+        if !invincible || dmg < 0 {
+            burger.hp -= dmg;
         }
-        state.burger.hp -= dmg;
     }
 }
 
-fn make_particles(pos: Vector2, particles: &mut Vec<Particle>, assets: &AssetLoader) {
-    let mut new = Particle::from_center(6, pos, 0.20, *assets.color("heart"));
-    particles.append(&mut new);
+pub(super) struct Effect {
+    pub(super) dmg:   i32,
+    pub(super) range: f64,
+    sound:            Option<SoundId>,
+    particles:        Option<ParticleEffect>
 }
 
-struct Effect {
-    dmg:            i32,
-    range:          f64,
-    sound:          Option<&'static str>,
-    make_particles: bool
-}
-
-mod effect {
+pub(super) mod effect {
     use super::Effect;
-    use crate::library::component::Class;
+    use crate::library::{ParticleEffect, SoundId, component::Class};
 
     fn zero() -> Effect {
         Effect {
-            dmg:            0,
-            range:          0.00,
-            sound:          None,
-            make_particles: false
+            dmg:       0,
+            range:     0.00,
+            sound:     None,
+            particles: None
         }
     }
 
@@ -75,13 +110,16 @@ mod effect {
             C::Slug => basic(7, 8.00),
             C::Flak => basic(5, 7.00),
             C::HealthPack => Effect {
-                dmg:            -4,
-                range:          7.00,
-                sound:          Some("heal"),
-                make_particles: true
+                dmg:       -4,
+                range:     7.00,
+                sound:     Some(SoundId::Heal),
+                particles: Some(ParticleEffect::Heal)
             },
             C::FlakChild => basic(2, 4.00),
-            C::Laser => basic(5, 3.00)
+            C::Laser => Effect {
+                particles: Some(ParticleEffect::LaserImpact),
+                ..basic(5, 3.00)
+            }
         })
     }
 }
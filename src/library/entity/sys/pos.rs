@@ -9,6 +9,8 @@ pub fn run(state: &mut State, dt: f64) {
         p.vel += p.acc * dt;
         p.pos += p.vel * dt;
     }
-    state.burger.vel += state.burger.acc * dt;
-    state.burger.pos += state.burger.vel * dt;
+    for burger in state.burgers.iter_mut().filter(|b| b.is_alive()) {
+        burger.vel += burger.acc * dt;
+        burger.pos += burger.vel * dt;
+    }
 }
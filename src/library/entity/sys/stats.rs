@@ -0,0 +1,10 @@
+use crate::State;
+
+pub fn run(state: &mut State, dt: f64) {
+    state.stats.time += dt;
+    state.stats.score = state.score;
+    state.stats.peak_difficulty = state.stats.peak_difficulty.max(state.difficulty);
+    if state.director.is_some() {
+        state.stats.track_curve(state.difficulty);
+    }
+}
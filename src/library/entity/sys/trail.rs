@@ -0,0 +1,15 @@
+use crate::State;
+
+pub fn run(state: &mut State) {
+    for burger in &mut state.burgers {
+        // only dashing makes the burger invulnerable
+        burger
+            .trail
+            .update(burger.pos, burger.is_alive() && burger.invuln > 0.00);
+    }
+    for e in &mut state.entities {
+        if let Some(trail) = &mut e.trail {
+            trail.update(e.pos, true);
+        }
+    }
+}
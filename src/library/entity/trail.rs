@@ -0,0 +1,77 @@
+use std::collections::VecDeque;
+
+use crate::*;
+
+/// Records recent positions of something fast, so it can be drawn with fading afterimages.
+///
+/// Positions are recorded whenever the tracked thing has moved at least `spacing` since the
+/// last one, which keeps the trail the same length regardless of framerate.
+pub struct Trail {
+    points:  VecDeque<Vector2>,
+    len:     usize,
+    spacing: f64
+}
+
+impl Trail {
+    pub fn new(len: usize, spacing: f64) -> Self {
+        Self {
+            points: VecDeque::with_capacity(len + 1),
+            len,
+            spacing
+        }
+    }
+    /// Returns the trail hazards of the given class opt into, if any.
+    pub fn for_class(class: component::Class) -> Option<Trail> {
+        use component::Class as C;
+        match class {
+            C::Slug => Some(Trail::new(3, 4.00)),
+            C::Laser => Some(Trail::new(4, 6.00)),
+            _ => None
+        }
+    }
+    /// Records the position while `active`. Otherwise, the trail shrinks by one afterimage.
+    pub fn update(&mut self, pos: Vector2, active: bool) {
+        if !active {
+            self.points.pop_back();
+            return;
+        }
+        let far_enough = self
+            .points
+            .front()
+            .is_none_or(|last| (*last - pos).len() >= self.spacing);
+        if far_enough {
+            self.points.push_front(pos);
+            self.points.truncate(self.len);
+        }
+    }
+    /// Returns the recorded positions from newest to oldest, along with the opacity to draw
+    /// each afterimage with.
+    pub fn ghosts(&self) -> impl Iterator<Item = (Vector2, f32)> + '_ {
+        let steps = (self.len + 1) as f32;
+        self.points
+            .iter()
+            .enumerate()
+            .map(move |(i, pos)| (*pos, 0.50 * (1.00 - (i + 1) as f32 / steps)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trail() {
+        let mut trail = Trail::new(2, 1.00);
+        trail.update(Vector2(0.00, 0.00), true);
+        trail.update(Vector2(0.50, 0.00), true);
+        assert_eq!(trail.ghosts().count(), 1);
+        trail.update(Vector2(2.00, 0.00), true);
+        trail.update(Vector2(4.00, 0.00), true);
+        let ghosts: Vec<_> = trail.ghosts().collect();
+        assert_eq!(ghosts.len(), 2);
+        assert!(ghosts[0].1 > ghosts[1].1);
+        trail.update(Vector2(4.00, 0.00), false);
+        trail.update(Vector2(4.00, 0.00), false);
+        assert_eq!(trail.ghosts().count(), 0);
+    }
+}
@@ -0,0 +1,124 @@
+//! The best runs on each difficulty [`Preset`].
+//!
+//! Every preset keeps its own table in [`paths::scores_dir`], named after the preset, with one
+//! `score time date` line per entry, best first. Runs with adaptive difficulty go into
+//! separate tables, marked `_adaptive`.
+
+use std::{fmt::Write, fs, io, path::PathBuf};
+
+use crate::library::{Preset, paths, today};
+
+/// Entries kept per table.
+pub const TABLE_SIZE: usize = 5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighScore {
+    pub score: i32,
+    /// Ticks survived.
+    pub time:  f64,
+    /// Day the run was played on, as `YYYY-MM-DD`.
+    pub date:  String
+}
+
+/// The high scores of one preset, with or without adaptive difficulty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighScores {
+    pub preset:   Preset,
+    pub adaptive: bool,
+    /// Best first.
+    pub entries:  Vec<HighScore>
+}
+
+impl HighScores {
+    /// Loads the table of the given preset, starting empty if there is none.
+    pub fn load(preset: Preset, adaptive: bool) -> HighScores {
+        let text = fs::read_to_string(HighScores::file(preset, adaptive)).unwrap_or_default();
+        HighScores::parse(&text, preset, adaptive)
+    }
+    /// Writes the table, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let path = HighScores::file(self.preset, self.adaptive);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_text())
+    }
+    /// Enters a run played today into the table, returning its rank from 0 if it made it in.
+    pub fn insert(&mut self, score: i32, time: f64) -> Option<usize> {
+        let rank = self
+            .entries
+            .iter()
+            .position(|entry| score > entry.score)
+            .unwrap_or(self.entries.len());
+        if rank >= TABLE_SIZE {
+            return None;
+        }
+        let date = today();
+        self.entries.insert(rank, HighScore { score, time, date });
+        self.entries.truncate(TABLE_SIZE);
+        Some(rank)
+    }
+
+    fn file(preset: Preset, adaptive: bool) -> PathBuf {
+        let adaptive = if adaptive { "_adaptive" } else { "" };
+        paths::scores_dir().join(format!("{preset}{adaptive}.txt"))
+    }
+
+    fn parse(text: &str, preset: Preset, adaptive: bool) -> HighScores {
+        let mut entries: Vec<HighScore> = text
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                Some(HighScore {
+                    score: fields.next()?.parse().ok()?,
+                    time:  fields.next()?.parse().ok()?,
+                    date:  fields.next()?.to_string()
+                })
+            })
+            .collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+        entries.truncate(TABLE_SIZE);
+        HighScores {
+            preset,
+            adaptive,
+            entries
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = String::new();
+        for entry in &self.entries {
+            writeln!(text, "{} {} {}", entry.score, entry.time, entry.date)
+                .expect("string write cannot fail");
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_high_scores_insert_and_round_trip() {
+        let mut scores = HighScores::parse(
+            "300 10 2026-01-01\nbroken\n500 20 2026-01-02",
+            Preset::Hard,
+            true
+        );
+        assert_eq!(scores.entries[0].score, 500);
+        assert_eq!(scores.insert(400, 5.00), Some(1));
+        for _ in 0..3 {
+            scores.insert(100, 1.00);
+        }
+        assert_eq!(scores.entries.len(), TABLE_SIZE);
+        assert_eq!(scores.insert(50, 1.00), None);
+
+        let loaded = HighScores::parse(&scores.to_text(), Preset::Hard, true);
+        assert_eq!(loaded, scores);
+    }
+}
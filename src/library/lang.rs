@@ -0,0 +1,415 @@
+//! Translated UI text.
+//!
+//! Every piece of text shown to the player has a [`TextId`], which is looked up in the string
+//! table of the chosen language at `assets/lang/<language>.txt`. Table files hold one
+//! `key = value` pair per line, where `\n` in a value starts a new line and each `{}` is
+//! replaced by an argument, in order. Missing entries fall back to English.
+
+use crate::library::{Action, pack};
+
+/// The language every other one falls back to.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Every piece of UI text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextId {
+    Paused,
+    Resume,
+    Controls,
+    Settings,
+    Players,
+    Restart,
+    GameOver,
+    RestartPrompt,
+    Defaults,
+    Back,
+    RebindCancel,
+    RebindRemove,
+    Master,
+    Music,
+    Sfx,
+    Scale,
+    Fullscreen,
+    Flash,
+    Colorblind,
+    Move,
+    Language,
+    On,
+    Off,
+    FlashFull,
+    FlashReduced,
+    SchemeKeys,
+    SchemeMouse,
+    ActionUp,
+    ActionDown,
+    ActionLeft,
+    ActionRight,
+    ActionDash,
+    ActionPause,
+    ActionRestart,
+    StatTime,
+    StatCheese,
+    StatDashes,
+    StatDamage,
+    StatDamageClass,
+    StatHeals,
+    StatDifficulty,
+    StatClosest,
+    ClassBullet,
+    ClassSlug,
+    ClassLaser,
+    ClassFlak,
+    ClassFlakChild,
+    Achievements,
+    Locked,
+    UnlockedOn,
+    AchievementToast,
+    CleanPlate,
+    CleanPlateDesc,
+    HotKitchen,
+    HotKitchenDesc,
+    LaserDancer,
+    LaserDancerDesc,
+    CloseShave,
+    CloseShaveDesc,
+    FullStack,
+    FullStackDesc,
+    SlowCooker,
+    SlowCookerDesc,
+    Title,
+    HighScores,
+    MainMenu,
+    PresetEasy,
+    PresetNormal,
+    PresetHard,
+    PresetInsane,
+    Adaptive,
+    Practice,
+    HazardBullets,
+    HazardSnakes,
+    HazardSlugs,
+    HazardLasers,
+    HazardFlak,
+    HazardCross,
+    PracticeDifficulty,
+    Invincible,
+    Start
+}
+
+impl TextId {
+    pub const COUNT: usize = 81;
+    pub const ALL: [TextId; Self::COUNT] = [
+        TextId::Paused,
+        TextId::Resume,
+        TextId::Controls,
+        TextId::Settings,
+        TextId::Players,
+        TextId::Restart,
+        TextId::GameOver,
+        TextId::RestartPrompt,
+        TextId::Defaults,
+        TextId::Back,
+        TextId::RebindCancel,
+        TextId::RebindRemove,
+        TextId::Master,
+        TextId::Music,
+        TextId::Sfx,
+        TextId::Scale,
+        TextId::Fullscreen,
+        TextId::Flash,
+        TextId::Colorblind,
+        TextId::Move,
+        TextId::Language,
+        TextId::On,
+        TextId::Off,
+        TextId::FlashFull,
+        TextId::FlashReduced,
+        TextId::SchemeKeys,
+        TextId::SchemeMouse,
+        TextId::ActionUp,
+        TextId::ActionDown,
+        TextId::ActionLeft,
+        TextId::ActionRight,
+        TextId::ActionDash,
+        TextId::ActionPause,
+        TextId::ActionRestart,
+        TextId::StatTime,
+        TextId::StatCheese,
+        TextId::StatDashes,
+        TextId::StatDamage,
+        TextId::StatDamageClass,
+        TextId::StatHeals,
+        TextId::StatDifficulty,
+        TextId::StatClosest,
+        TextId::ClassBullet,
+        TextId::ClassSlug,
+        TextId::ClassLaser,
+        TextId::ClassFlak,
+        TextId::ClassFlakChild,
+        TextId::Achievements,
+        TextId::Locked,
+        TextId::UnlockedOn,
+        TextId::AchievementToast,
+        TextId::CleanPlate,
+        TextId::CleanPlateDesc,
+        TextId::HotKitchen,
+        TextId::HotKitchenDesc,
+        TextId::LaserDancer,
+        TextId::LaserDancerDesc,
+        TextId::CloseShave,
+        TextId::CloseShaveDesc,
+        TextId::FullStack,
+        TextId::FullStackDesc,
+        TextId::SlowCooker,
+        TextId::SlowCookerDesc,
+        TextId::Title,
+        TextId::HighScores,
+        TextId::MainMenu,
+        TextId::PresetEasy,
+        TextId::PresetNormal,
+        TextId::PresetHard,
+        TextId::PresetInsane,
+        TextId::Adaptive,
+        TextId::Practice,
+        TextId::HazardBullets,
+        TextId::HazardSnakes,
+        TextId::HazardSlugs,
+        TextId::HazardLasers,
+        TextId::HazardFlak,
+        TextId::HazardCross,
+        TextId::PracticeDifficulty,
+        TextId::Invincible,
+        TextId::Start
+    ];
+
+    /// Returns the key of the text in the string tables.
+    pub fn key(self) -> &'static str {
+        match self {
+            TextId::Paused => "paused",
+            TextId::Resume => "resume",
+            TextId::Controls => "controls",
+            TextId::Settings => "settings",
+            TextId::Players => "players",
+            TextId::Restart => "restart",
+            TextId::GameOver => "game_over",
+            TextId::RestartPrompt => "restart_prompt",
+            TextId::Defaults => "defaults",
+            TextId::Back => "back",
+            TextId::RebindCancel => "controls.cancel",
+            TextId::RebindRemove => "controls.remove",
+            TextId::Master => "volume.master",
+            TextId::Music => "volume.music",
+            TextId::Sfx => "volume.sfx",
+            TextId::Scale => "window.scale",
+            TextId::Fullscreen => "window.fullscreen",
+            TextId::Flash => "display.flash",
+            TextId::Colorblind => "display.colorblind",
+            TextId::Move => "controls.scheme",
+            TextId::Language => "language",
+            TextId::On => "on",
+            TextId::Off => "off",
+            TextId::FlashFull => "flash.full",
+            TextId::FlashReduced => "flash.reduced",
+            TextId::SchemeKeys => "scheme.keys",
+            TextId::SchemeMouse => "scheme.mouse",
+            TextId::ActionUp => "action.up",
+            TextId::ActionDown => "action.down",
+            TextId::ActionLeft => "action.left",
+            TextId::ActionRight => "action.right",
+            TextId::ActionDash => "action.dash",
+            TextId::ActionPause => "action.pause",
+            TextId::ActionRestart => "action.restart",
+            TextId::StatTime => "stats.time",
+            TextId::StatCheese => "stats.cheese",
+            TextId::StatDashes => "stats.dashes",
+            TextId::StatDamage => "stats.damage",
+            TextId::StatDamageClass => "stats.damage_class",
+            TextId::StatHeals => "stats.heals",
+            TextId::StatDifficulty => "stats.difficulty",
+            TextId::StatClosest => "stats.closest",
+            TextId::ClassBullet => "class.bullet",
+            TextId::ClassSlug => "class.slug",
+            TextId::ClassLaser => "class.laser",
+            TextId::ClassFlak => "class.flak",
+            TextId::ClassFlakChild => "class.flak_child",
+            TextId::Achievements => "achievements",
+            TextId::Locked => "achievements.locked",
+            TextId::UnlockedOn => "achievements.unlocked_on",
+            TextId::AchievementToast => "achievements.toast",
+            TextId::CleanPlate => "achievement.clean_plate",
+            TextId::CleanPlateDesc => "achievement.clean_plate.desc",
+            TextId::HotKitchen => "achievement.hot_kitchen",
+            TextId::HotKitchenDesc => "achievement.hot_kitchen.desc",
+            TextId::LaserDancer => "achievement.laser_dancer",
+            TextId::LaserDancerDesc => "achievement.laser_dancer.desc",
+            TextId::CloseShave => "achievement.close_shave",
+            TextId::CloseShaveDesc => "achievement.close_shave.desc",
+            TextId::FullStack => "achievement.full_stack",
+            TextId::FullStackDesc => "achievement.full_stack.desc",
+            TextId::SlowCooker => "achievement.slow_cooker",
+            TextId::SlowCookerDesc => "achievement.slow_cooker.desc",
+            TextId::Title => "title",
+            TextId::HighScores => "high_scores",
+            TextId::MainMenu => "main_menu",
+            TextId::PresetEasy => "preset.easy",
+            TextId::PresetNormal => "preset.normal",
+            TextId::PresetHard => "preset.hard",
+            TextId::PresetInsane => "preset.insane",
+            TextId::Adaptive => "difficulty.adaptive",
+            TextId::Practice => "practice",
+            TextId::HazardBullets => "hazard.bullets",
+            TextId::HazardSnakes => "hazard.snakes",
+            TextId::HazardSlugs => "hazard.slugs",
+            TextId::HazardLasers => "hazard.lasers",
+            TextId::HazardFlak => "hazard.flak",
+            TextId::HazardCross => "hazard.cross",
+            TextId::PracticeDifficulty => "practice.difficulty",
+            TextId::Invincible => "practice.invincible",
+            TextId::Start => "start"
+        }
+    }
+    /// Returns the name of an action.
+    pub fn action(action: Action) -> TextId {
+        match action {
+            Action::Up => TextId::ActionUp,
+            Action::Down => TextId::ActionDown,
+            Action::Left => TextId::ActionLeft,
+            Action::Right => TextId::ActionRight,
+            Action::Dash => TextId::ActionDash,
+            Action::Pause => TextId::ActionPause,
+            Action::Restart => TextId::ActionRestart
+        }
+    }
+    /// Returns the name of a hazard class, given its
+    /// [`Class::name`](crate::library::component::Class::name).
+    pub fn class(name: &str) -> Option<TextId> {
+        Some(match name {
+            "bullet" => TextId::ClassBullet,
+            "slug" => TextId::ClassSlug,
+            "laser" => TextId::ClassLaser,
+            "flak" => TextId::ClassFlak,
+            "flak_child" => TextId::ClassFlakChild,
+            _ => return None
+        })
+    }
+    /// Returns "on" or "off".
+    pub fn on_off(value: bool) -> TextId {
+        if value { TextId::On } else { TextId::Off }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The string table of one language.
+#[derive(Debug, Clone)]
+pub struct Strings {
+    pub language: String,
+    texts:        Vec<String>
+}
+
+impl Strings {
+    /// Loads the string table of the given language, falling back to English for anything it
+    /// lacks, or entirely if there is no such language.
+    pub fn load(language: &str) -> Strings {
+        let fallback =
+            table(DEFAULT_LANGUAGE).expect("the default language should be in the asset pack");
+        let mut strings = Strings::parse(&fallback, DEFAULT_LANGUAGE, None);
+        if language != DEFAULT_LANGUAGE
+            && let Some(text) = table(language)
+        {
+            strings = Strings::parse(&text, language, Some(strings));
+        }
+        strings
+    }
+    /// Lists the languages there are string tables for, sorted.
+    pub fn languages() -> Vec<String> {
+        pack::list("assets/lang")
+            .into_iter()
+            .filter_map(|name| name.strip_suffix(".txt").map(str::to_string))
+            .collect()
+    }
+    /// Returns the text with the given ID.
+    pub fn get(&self, id: TextId) -> &str {
+        &self.texts[id.index()]
+    }
+    /// Returns the text with the given ID, with each `{}` replaced by the next argument.
+    pub fn fmt(&self, id: TextId, args: &[&str]) -> String {
+        let mut parts = self.get(id).split("{}");
+        let mut text = parts.next().unwrap_or_default().to_string();
+        for (i, part) in parts.enumerate() {
+            text.push_str(args.get(i).copied().unwrap_or_default());
+            text.push_str(part);
+        }
+        text
+    }
+
+    fn parse(text: &str, language: &str, fallback: Option<Strings>) -> Strings {
+        let mut texts = fallback.map_or_else(
+            || TextId::ALL.iter().map(|id| id.key().to_string()).collect(),
+            |fallback| fallback.texts
+        );
+        let pairs = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()));
+        for (key, value) in pairs {
+            if let Some(id) = TextId::ALL.into_iter().find(|id| id.key() == key) {
+                texts[id.index()] = value.replace("\\n", "\n");
+            }
+        }
+        Strings {
+            language: language.to_string(),
+            texts
+        }
+    }
+}
+
+fn table(language: &str) -> Option<String> {
+    let bytes = pack::read(&format!("assets/lang/{language}.txt"))?;
+    String::from_utf8(bytes.into_owned()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_ids_match() {
+        assert!(
+            TextId::ALL
+                .iter()
+                .enumerate()
+                .all(|(i, id)| id.index() == i)
+        );
+    }
+
+    #[test]
+    fn test_strings_fallback_and_fmt() {
+        let english = Strings::parse("players = players: {}\nback = back", "en", None);
+        let strings = Strings::parse("back = zurück\ngame_over = a\\nb", "de", Some(english));
+        assert_eq!(strings.get(TextId::Back), "zurück");
+        assert_eq!(strings.get(TextId::GameOver), "a\nb");
+        assert_eq!(strings.fmt(TextId::Players, &["2"]), "players: 2");
+        assert_eq!(strings.get(TextId::Resume), "resume");
+    }
+
+    #[test]
+    fn test_languages_are_complete() {
+        for language in Strings::languages() {
+            let text = table(&language).expect("listed languages should load");
+            for id in TextId::ALL {
+                assert!(
+                    text.lines().any(|line| line
+                        .split_once('=')
+                        .is_some_and(|(key, _)| key.trim() == id.key())),
+                    "{language} is missing {}",
+                    id.key()
+                );
+            }
+        }
+    }
+}
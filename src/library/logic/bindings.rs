@@ -0,0 +1,387 @@
+use macroquad::prelude::*;
+
+use super::{Gamepad, PadButton};
+
+/// Everything the player can do, independent of which keys or buttons do it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Up,
+    Down,
+    Left,
+    Right,
+    Dash,
+    Pause,
+    Restart
+}
+
+impl Action {
+    pub const COUNT: usize = 7;
+    pub const ALL: [Action; Self::COUNT] = [
+        Action::Up,
+        Action::Down,
+        Action::Left,
+        Action::Right,
+        Action::Dash,
+        Action::Pause,
+        Action::Restart
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::Dash => "dash",
+            Action::Pause => "pause",
+            Action::Restart => "restart"
+        }
+    }
+}
+
+/// A single key or gamepad button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Binding {
+    Key(KeyCode),
+    Pad(PadButton)
+}
+
+impl Binding {
+    /// Returns a binding to the key, or `None` if the key cannot be bound.
+    pub fn key(key: KeyCode) -> Option<Binding> {
+        BINDABLE_KEYS.contains(&key).then_some(Binding::Key(key))
+    }
+    /// Returns whether both bindings are keys, or both are gamepad buttons.
+    pub fn same_kind(self, other: Binding) -> bool {
+        matches!(
+            (self, other),
+            (Binding::Key(_), Binding::Key(_)) | (Binding::Pad(_), Binding::Pad(_))
+        )
+    }
+    /// Returns the name of the binding as written to the settings file.
+    pub fn name(self) -> String {
+        match self {
+            Binding::Key(key) => format!("{key:?}"),
+            Binding::Pad(button) => format!("Pad{button:?}")
+        }
+    }
+    /// Returns a short label for the binding, for showing it on screen.
+    pub fn label(self) -> String {
+        use KeyCode as K;
+        use PadButton as P;
+        let label = match self {
+            Binding::Key(key) => match key {
+                K::Escape => "esc",
+                K::Backspace => "bksp",
+                K::LeftShift => "lshift",
+                K::RightShift => "rshift",
+                K::LeftControl => "lctrl",
+                K::RightControl => "rctrl",
+                K::LeftAlt => "lalt",
+                K::RightAlt => "ralt",
+                K::Comma => ",",
+                K::Period => ".",
+                K::Slash => "/",
+                K::Semicolon => ";",
+                K::Apostrophe => "'",
+                key => {
+                    let name = format!("{key:?}").to_lowercase();
+                    // digits are named Key0 to Key9
+                    return name.strip_prefix("key").unwrap_or(&name).to_string();
+                }
+            },
+            Binding::Pad(button) => match button {
+                P::South => "a",
+                P::East => "b",
+                P::West => "x",
+                P::North => "y",
+                P::LeftShoulder => "lb",
+                P::RightShoulder => "rb",
+                P::Select => "back",
+                P::Start => "start",
+                P::DPadUp => "d-up",
+                P::DPadDown => "d-dn",
+                P::DPadLeft => "d-lt",
+                P::DPadRight => "d-rt"
+            }
+        };
+        label.to_string()
+    }
+    /// Parses a name written by [`Binding::name`].
+    pub fn parse(name: &str) -> Option<Binding> {
+        if let Some(button) = name.strip_prefix("Pad") {
+            PadButton::ALL
+                .into_iter()
+                .find(|b| format!("{b:?}") == button)
+                .map(Binding::Pad)
+        } else {
+            BINDABLE_KEYS
+                .into_iter()
+                .find(|k| format!("{k:?}") == name)
+                .map(Binding::Key)
+        }
+    }
+}
+
+/// Keys that can be bound to actions. Backspace and delete are missing, since they cancel and
+/// remove bindings on the rebinding screen.
+const BINDABLE_KEYS: [KeyCode; 55] = {
+    use KeyCode as K;
+    [
+        K::A,
+        K::B,
+        K::C,
+        K::D,
+        K::E,
+        K::F,
+        K::G,
+        K::H,
+        K::I,
+        K::J,
+        K::K,
+        K::L,
+        K::M,
+        K::N,
+        K::O,
+        K::P,
+        K::Q,
+        K::R,
+        K::S,
+        K::T,
+        K::U,
+        K::V,
+        K::W,
+        K::X,
+        K::Y,
+        K::Z,
+        K::Key0,
+        K::Key1,
+        K::Key2,
+        K::Key3,
+        K::Key4,
+        K::Key5,
+        K::Key6,
+        K::Key7,
+        K::Key8,
+        K::Key9,
+        K::Up,
+        K::Down,
+        K::Left,
+        K::Right,
+        K::Space,
+        K::Enter,
+        K::Escape,
+        K::Tab,
+        K::LeftShift,
+        K::RightShift,
+        K::LeftControl,
+        K::RightControl,
+        K::LeftAlt,
+        K::RightAlt,
+        K::Comma,
+        K::Period,
+        K::Slash,
+        K::Semicolon,
+        K::Apostrophe
+    ]
+};
+
+/// Maps every [`Action`] onto the keys and buttons that trigger it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bindings([Vec<Binding>; Action::COUNT]);
+
+impl Default for Bindings {
+    fn default() -> Self {
+        use Binding::{Key, Pad};
+        use KeyCode as K;
+        use PadButton as P;
+        Self([
+            vec![Key(K::W), Key(K::Up), Pad(P::DPadUp)],
+            vec![Key(K::S), Key(K::Down), Pad(P::DPadDown)],
+            vec![Key(K::A), Key(K::Left), Pad(P::DPadLeft)],
+            vec![Key(K::D), Key(K::Right), Pad(P::DPadRight)],
+            vec![Key(K::Space), Pad(P::South)],
+            vec![Key(K::Escape), Key(K::P), Pad(P::Start)],
+            vec![Key(K::R), Pad(P::Select)]
+        ])
+    }
+}
+
+impl Bindings {
+    pub fn get(&self, action: Action) -> &[Binding] {
+        &self.0[action as usize]
+    }
+    /// Returns the first key bound to the action, if any.
+    pub fn primary_key(&self, action: Action) -> Option<Binding> {
+        self.get(action)
+            .iter()
+            .copied()
+            .find(|b| matches!(b, Binding::Key(_)))
+    }
+    /// Returns the first gamepad button bound to the action, if any.
+    pub fn primary_button(&self, action: Action) -> Option<Binding> {
+        self.get(action)
+            .iter()
+            .copied()
+            .find(|b| matches!(b, Binding::Pad(_)))
+    }
+    pub fn set(&mut self, action: Action, bindings: Vec<Binding>) {
+        self.0[action as usize] = bindings;
+    }
+    /// Replaces the binding of the action at `slot` with the given one, or adds it if `slot`
+    /// is past the last binding. The binding is taken away from any other action using it, so
+    /// it only ever does one thing.
+    ///
+    /// Changes nothing and returns `false` if that would leave an action without a key or
+    /// button it had before.
+    pub fn rebind(&mut self, action: Action, slot: usize, binding: Binding) -> bool {
+        let bindings = self.get(action);
+        if bindings.contains(&binding) {
+            return true;
+        }
+        if let Some(&old) = bindings.get(slot)
+            && !old.same_kind(binding)
+            && !self.can_remove(action, old)
+        {
+            return false;
+        }
+        let others = Action::ALL.into_iter().filter(|&other| other != action);
+        if !others.clone().all(|other| self.can_remove(other, binding)) {
+            return false;
+        }
+        for other in others {
+            self.0[other as usize].retain(|&b| b != binding);
+        }
+        let bindings = &mut self.0[action as usize];
+        match bindings.get_mut(slot) {
+            Some(old) => *old = binding,
+            None => bindings.push(binding)
+        }
+        true
+    }
+    /// Removes the binding of the action at `slot`.
+    ///
+    /// Changes nothing and returns `false` if there is no such binding, or if it is the last
+    /// key or button of the action.
+    pub fn unbind(&mut self, action: Action, slot: usize) -> bool {
+        match self.get(action).get(slot) {
+            Some(&binding) if self.can_remove(action, binding) => {
+                self.0[action as usize].remove(slot);
+                true
+            }
+            _ => false
+        }
+    }
+
+    /// Returns whether taking the binding away from the action leaves it with a binding of
+    /// the same kind, or whether the action doesn't use the binding at all.
+    fn can_remove(&self, action: Action, binding: Binding) -> bool {
+        let bindings = self.get(action);
+        !bindings.contains(&binding) || bindings.iter().filter(|b| b.same_kind(binding)).count() > 1
+    }
+}
+
+/// Which devices [`Controls`] listen to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Device {
+    Any,
+    Keyboard,
+    Gamepad
+}
+
+/// The bindings together with the gamepad, for checking actions.
+#[derive(Clone, Copy)]
+pub struct Controls<'a> {
+    pub bindings: &'a Bindings,
+    pub gamepad:  &'a Gamepad,
+    pub device:   Device
+}
+
+impl<'a> Controls<'a> {
+    /// Creates controls listening to every device.
+    pub fn new(bindings: &'a Bindings, gamepad: &'a Gamepad) -> Self {
+        Self {
+            bindings,
+            gamepad,
+            device: Device::Any
+        }
+    }
+    /// Returns the same controls, only listening to the given device.
+    #[must_use]
+    pub fn only(self, device: Device) -> Self {
+        Self { device, ..self }
+    }
+    pub fn uses_gamepad(&self) -> bool {
+        self.device != Device::Keyboard
+    }
+    /// Returns whether any binding of the action is held down.
+    pub fn is_down(&self, action: Action) -> bool {
+        self.bindings.get(action).iter().any(|b| match *b {
+            Binding::Key(key) => self.device != Device::Gamepad && is_key_down(key),
+            Binding::Pad(button) => self.uses_gamepad() && self.gamepad.is_down(button)
+        })
+    }
+    /// Returns whether any binding of the action went down this frame.
+    pub fn is_pressed(&self, action: Action) -> bool {
+        self.bindings.get(action).iter().any(|b| match *b {
+            Binding::Key(key) => self.device != Device::Gamepad && is_key_pressed(key),
+            Binding::Pad(button) => self.uses_gamepad() && self.gamepad.is_pressed(button)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binding_names() {
+        for bindings in Bindings::default().0 {
+            for binding in bindings {
+                assert_eq!(Binding::parse(&binding.name()), Some(binding));
+            }
+        }
+        assert_eq!(Binding::parse("NotAKey"), None);
+    }
+
+    #[test]
+    fn test_bindable_keys_round_trip() {
+        for key in BINDABLE_KEYS {
+            let binding = Binding::key(key).unwrap();
+            assert_eq!(Binding::parse(&binding.name()), Some(binding));
+        }
+        assert_eq!(Binding::key(KeyCode::F1), None);
+        assert_eq!(Binding::key(KeyCode::Kp5), None);
+    }
+
+    #[test]
+    fn test_rebind_slots() {
+        let mut bindings = Bindings::default();
+        assert!(bindings.rebind(Action::Up, 1, Binding::Key(KeyCode::I)));
+        assert!(bindings.rebind(Action::Up, 3, Binding::Pad(PadButton::North)));
+        assert!(bindings.unbind(Action::Up, 0));
+        assert_eq!(
+            bindings.get(Action::Up),
+            [
+                Binding::Key(KeyCode::I),
+                Binding::Pad(PadButton::DPadUp),
+                Binding::Pad(PadButton::North)
+            ]
+        );
+        // the last key can't go, neither by removing nor by replacing it with a button
+        assert!(!bindings.unbind(Action::Up, 0));
+        assert!(!bindings.rebind(Action::Up, 0, Binding::Pad(PadButton::East)));
+        assert!(!bindings.unbind(Action::Up, 9));
+    }
+
+    #[test]
+    fn test_rebind_takes_binding_from_others() {
+        let mut bindings = Bindings::default();
+        assert!(bindings.rebind(Action::Left, 0, Binding::Key(KeyCode::W)));
+        assert_eq!(bindings.get(Action::Left)[0], Binding::Key(KeyCode::W));
+        assert!(!bindings.get(Action::Up).contains(&Binding::Key(KeyCode::W)));
+        // taking the only key of dash is refused
+        assert!(!bindings.rebind(Action::Up, 0, Binding::Key(KeyCode::Space)));
+        assert_eq!(bindings.get(Action::Dash)[0], Binding::Key(KeyCode::Space));
+    }
+}
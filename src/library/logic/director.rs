@@ -0,0 +1,110 @@
+use crate::library::RunStats;
+
+/// Ticks over which damage and cheese count as recent. Older ones fade out exponentially.
+const MEMORY: f64 = 600.00;
+/// Recent damage at which the director eases off the most.
+const DAMAGE_SCALE: f64 = 4.00;
+/// Recent cheese of a player keeping up, one every 5 seconds.
+const CHEESE_TARGET: f64 = MEMORY / 300.00;
+/// How far the difficulty may be nudged, as factors on the linear ramp.
+const NUDGE_MIN: f64 = -0.35;
+const NUDGE_MAX: f64 = 0.50;
+/// Nudge per tick the director moves towards its target with.
+const NUDGE_SPEED: f64 = 0.0010;
+
+/// Adapts the difficulty to how the players are doing.
+///
+/// The difficulty still ramps up linearly, but is nudged down while the players take damage,
+/// run low on health or fall behind on cheese, and up while they cruise. The nudge moves
+/// slowly and stays within bounds, so runs neither stall nor spike.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Director {
+    /// Difficulty without any nudging.
+    base:          f64,
+    /// Current nudge, as a factor on the base difficulty.
+    nudge:         f64,
+    recent_damage: f64,
+    recent_cheese: f64,
+    /// Totals already seen in the run statistics.
+    seen_damage:   i32,
+    seen_cheese:   u32
+}
+
+impl Director {
+    pub fn new(start_difficulty: f64) -> Self {
+        Self {
+            base:          start_difficulty,
+            nudge:         0.00,
+            recent_damage: 0.00,
+            // assume the players keep up until they show otherwise
+            recent_cheese: CHEESE_TARGET,
+            seen_damage:   0,
+            seen_cheese:   0
+        }
+    }
+    /// Ramps up the base difficulty and moves the nudge towards how well the run is going.
+    ///
+    /// `health` is the lowest share of health left among living players, between 0 and 1.
+    pub fn update(&mut self, dt: f64, ramp: f64, stats: &RunStats, health: f64) {
+        self.base += ramp * dt;
+
+        let fade = (-dt / MEMORY).exp();
+        let damage = stats.total_damage();
+        self.recent_damage = self.recent_damage * fade + f64::from(damage - self.seen_damage);
+        self.recent_cheese = self.recent_cheese * fade + f64::from(stats.cheese - self.seen_cheese);
+        self.seen_damage = damage;
+        self.seen_cheese = stats.cheese;
+
+        let target = self.pressure(health);
+        let step = NUDGE_SPEED * dt;
+        self.nudge = (target - self.nudge).clamp(-step, step) + self.nudge;
+    }
+    pub fn difficulty(&self) -> f64 {
+        self.base * (1.00 + self.nudge)
+    }
+
+    /// Returns the nudge the run calls for, from easing off fully to pushing fully.
+    fn pressure(&self, health: f64) -> f64 {
+        let health = (health.clamp(0.00, 1.00) - 0.50) * 2.00;
+        let damage = -(self.recent_damage / DAMAGE_SCALE).min(1.00);
+        let cheese = (self.recent_cheese / CHEESE_TARGET - 1.00).clamp(-1.00, 1.00);
+        let skill = (health + damage + cheese) / 3.00;
+        if skill < 0.00 {
+            -skill * NUDGE_MIN
+        } else {
+            skill * NUDGE_MAX
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(director: &mut Director, ticks: u32, stats: &mut RunStats, hit: bool, health: f64) {
+        for tick in 0..ticks {
+            if tick % 120 == 0 {
+                if hit {
+                    stats.track_damage("bullet", 1);
+                } else {
+                    stats.track_cheese();
+                }
+            }
+            director.update(1.00, 0.10, stats, health);
+        }
+    }
+
+    #[test]
+    fn test_director_eases_off_and_pushes() {
+        let mut struggling = Director::new(100.00);
+        run(&mut struggling, 3000, &mut RunStats::default(), true, 0.25);
+        let base = struggling.base;
+        assert!(struggling.difficulty() < base);
+        assert!(struggling.difficulty() >= base * (1.00 + NUDGE_MIN));
+
+        let mut cruising = Director::new(100.00);
+        run(&mut cruising, 3000, &mut RunStats::default(), false, 1.00);
+        assert!(cruising.difficulty() > base);
+        assert!(cruising.difficulty() <= base * (1.00 + NUDGE_MAX));
+    }
+}
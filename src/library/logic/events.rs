@@ -0,0 +1,10 @@
+use crate::library::component::Class;
+
+/// Something notable that happened during a tick, for systems outside the game loop such as
+/// achievements to react to.
+#[derive(Clone, Copy)]
+pub enum GameEvent {
+    /// A dashing burger passed through a hazard of the given class unharmed. Sent once per
+    /// hazard.
+    DashedThrough(Class)
+}
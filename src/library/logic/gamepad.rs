@@ -0,0 +1,217 @@
+//! Minimal gamepad support.
+//!
+//! Macroquad does not read gamepads, so this goes through [`gilrs`], which covers Linux,
+//! Windows and macOS. On Linux it needs libudev, both to build and to run. Gamepads can be
+//! plugged in and out while the game runs.
+
+use gilrs::{Axis, Button, EventType, Gilrs};
+
+use crate::vector::Vector2;
+
+/// Sticks report positions within this radius of the center as neutral.
+const DEADZONE: f64 = 0.20;
+
+/// Gamepad buttons, named by their position on an Xbox-style layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadButton {
+    South,
+    East,
+    West,
+    North,
+    LeftShoulder,
+    RightShoulder,
+    Select,
+    Start,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight
+}
+
+impl PadButton {
+    pub const ALL: [PadButton; 12] = [
+        PadButton::South,
+        PadButton::East,
+        PadButton::West,
+        PadButton::North,
+        PadButton::LeftShoulder,
+        PadButton::RightShoulder,
+        PadButton::Select,
+        PadButton::Start,
+        PadButton::DPadUp,
+        PadButton::DPadDown,
+        PadButton::DPadLeft,
+        PadButton::DPadRight
+    ];
+
+    fn gilrs(self) -> Button {
+        match self {
+            PadButton::South => Button::South,
+            PadButton::East => Button::East,
+            PadButton::West => Button::West,
+            PadButton::North => Button::North,
+            PadButton::LeftShoulder => Button::LeftTrigger,
+            PadButton::RightShoulder => Button::RightTrigger,
+            PadButton::Select => Button::Select,
+            PadButton::Start => Button::Start,
+            PadButton::DPadUp => Button::DPadUp,
+            PadButton::DPadDown => Button::DPadDown,
+            PadButton::DPadLeft => Button::DPadLeft,
+            PadButton::DPadRight => Button::DPadRight
+        }
+    }
+}
+
+/// The raw state of a gamepad.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PadState {
+    pub connected: bool,
+    /// Left stick position, with both components between -1 and 1. Down is positive y.
+    pub stick:     Vector2,
+    buttons:       u32
+}
+
+impl PadState {
+    pub fn is_down(&self, button: PadButton) -> bool {
+        self.buttons & (1 << button as u32) != 0
+    }
+    fn set(&mut self, button: PadButton, down: bool) {
+        if down {
+            self.buttons |= 1 << button as u32;
+        } else {
+            self.buttons &= !(1 << button as u32);
+        }
+    }
+}
+
+/// The first connected gamepad, if any.
+pub struct Gamepad {
+    /// `None` if the platform's gamepad support failed to start.
+    gilrs:  Option<Gilrs>,
+    active: Option<gilrs::GamepadId>,
+    state:  PadState,
+    last:   PadState
+}
+
+impl Default for Gamepad {
+    fn default() -> Self {
+        Self::disconnected()
+    }
+}
+
+impl Gamepad {
+    /// Starts the platform's gamepad support.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the platform's gamepad support is missing or fails to start.
+    pub fn new() -> Result<Self, Box<gilrs::Error>> {
+        Ok(Self {
+            gilrs: Some(Gilrs::new().map_err(Box::new)?),
+            ..Self::disconnected()
+        })
+    }
+    /// A gamepad that never connects, for when gamepad support failed to start.
+    pub fn disconnected() -> Self {
+        Self {
+            gilrs:  None,
+            active: None,
+            state:  PadState::default(),
+            last:   PadState::default()
+        }
+    }
+    /// Updates the gamepad state. Should be called once per frame.
+    pub fn poll(&mut self) {
+        self.last = self.state;
+        let Some(gilrs) = &mut self.gilrs else {
+            return;
+        };
+        // gilrs only updates the state of its gamepads while its events are drained
+        while let Some(event) = gilrs.next_event() {
+            match event.event {
+                EventType::Disconnected if self.active == Some(event.id) => self.active = None,
+                EventType::Disconnected => (),
+                _ => {
+                    self.active.get_or_insert(event.id);
+                }
+            }
+        }
+        if self.active.is_none() {
+            self.active = gilrs.gamepads().next().map(|(id, _)| id);
+        }
+        self.state = self
+            .active
+            .and_then(|id| gilrs.connected_gamepad(id))
+            .map_or_else(PadState::default, read_state);
+    }
+    pub fn is_connected(&self) -> bool {
+        self.state.connected
+    }
+    pub fn is_down(&self, button: PadButton) -> bool {
+        self.state.is_down(button)
+    }
+    /// Returns whether the button went down since the last poll.
+    pub fn is_pressed(&self, button: PadButton) -> bool {
+        self.state.is_down(button) && !self.last.is_down(button)
+    }
+    /// Returns the first button that went down since the last poll, if any.
+    pub fn last_pressed(&self) -> Option<PadButton> {
+        PadButton::ALL.into_iter().find(|&b| self.is_pressed(b))
+    }
+    /// Returns the left stick position with the deadzone applied, no longer than 1.
+    pub fn stick(&self) -> Vector2 {
+        apply_deadzone(self.state.stick)
+    }
+}
+
+fn read_state(pad: gilrs::Gamepad) -> PadState {
+    let mut state = PadState {
+        connected: true,
+        // gilrs has up as positive y
+        stick:     Vector2(
+            f64::from(pad.value(Axis::LeftStickX)),
+            -f64::from(pad.value(Axis::LeftStickY))
+        ),
+        buttons:   0
+    };
+    for button in PadButton::ALL {
+        state.set(button, pad.is_pressed(button.gilrs()));
+    }
+    state
+}
+
+/// Zeroes out positions within the deadzone, and rescales the rest so that movement starts
+/// smoothly from 0 at its edge. The output never exceeds a length of 1.
+fn apply_deadzone(stick: Vector2) -> Vector2 {
+    let len = stick.len();
+    if len < DEADZONE {
+        return Vector2::ZERO;
+    }
+    let scaled = ((len - DEADZONE) / (1.00 - DEADZONE)).min(1.00);
+    stick.normal() * scaled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_deadzone() {
+        assert!(apply_deadzone(Vector2(0.10, -0.10)).len() < 1e-10);
+        assert!((apply_deadzone(Vector2(1.00, 0.00)).len() - 1.00).abs() < 1e-10);
+        // corners of square gates get pulled back onto the unit circle
+        assert!((apply_deadzone(Vector2(1.00, 1.00)).len() - 1.00).abs() < 1e-10);
+        let halfway = apply_deadzone(Vector2(0.00, DEADZONE + (1.00 - DEADZONE) * 0.50));
+        assert!((halfway.y() - 0.50).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_pad_buttons() {
+        let mut state = PadState::default();
+        state.set(PadButton::South, true);
+        state.set(PadButton::Start, true);
+        state.set(PadButton::South, false);
+        assert!(!state.is_down(PadButton::South));
+        assert!(state.is_down(PadButton::Start));
+    }
+}
@@ -1,27 +1,99 @@
-use macroquad::prelude::*;
+use std::{fmt, str::FromStr};
 
+use macroquad::input::{MouseButton, is_mouse_button_down};
+
+use super::{Action, Controls};
 use crate::vector::Vector2;
 
+/// Distance from the cursor within which mouse controls start slowing the burger down.
+const MOUSE_SLOWDOWN_RADIUS: f64 = 12.00;
+
+/// Where movement comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlScheme {
+    /// Keyboard and gamepad, see [`Input::get`].
+    Keys,
+    /// The burger follows the cursor, see [`Input::from_mouse`].
+    Mouse
+}
+
+impl ControlScheme {
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            ControlScheme::Keys => ControlScheme::Mouse,
+            ControlScheme::Mouse => ControlScheme::Keys
+        }
+    }
+}
+
+impl fmt::Display for ControlScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ControlScheme::Keys => "keys",
+            ControlScheme::Mouse => "mouse"
+        })
+    }
+}
+
+impl FromStr for ControlScheme {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keys" => Ok(ControlScheme::Keys),
+            "mouse" => Ok(ControlScheme::Mouse),
+            _ => Err(())
+        }
+    }
+}
+
+/// The inputs for a single frame, merged from the keyboard and the gamepad.
 pub struct Input {
-    pub w:     Button,
-    pub a:     Button,
-    pub s:     Button,
-    pub d:     Button,
-    pub space: Button
+    dir:      Vector2,
+    pub dash: Button
 }
 impl Input {
-    pub fn get() -> Input {
+    pub fn get(controls: Controls) -> Input {
+        let held = |action| f64::from(Button::from(controls.is_down(action)));
+
+        let digital = Vector2(
+            held(Action::Right) - held(Action::Left),
+            held(Action::Down) - held(Action::Up)
+        );
+        // digital directions take priority over the stick
+        let dir = if digital.len() > 0.00 {
+            digital.normal()
+        } else if controls.uses_gamepad() {
+            controls.gamepad.stick()
+        } else {
+            Vector2::ZERO
+        };
+
+        Input {
+            dir,
+            dash: controls.is_down(Action::Dash).into()
+        }
+    }
+    /// Moves towards the cursor, given in arena coordinates, and dashes on click.
+    ///
+    /// The bound dash action still works, so the keyboard can be used alongside the mouse.
+    pub fn from_mouse(controls: Controls, cursor: Vector2, burger_pos: Vector2) -> Input {
+        let to_cursor = cursor - burger_pos;
+        let dir = to_cursor.normal() * (to_cursor.len() / MOUSE_SLOWDOWN_RADIUS).min(1.00);
+        let clicked = is_mouse_button_down(MouseButton::Left);
+
         Input {
-            w:     is_key_down(KeyCode::W).into(),
-            a:     is_key_down(KeyCode::A).into(),
-            s:     is_key_down(KeyCode::S).into(),
-            d:     is_key_down(KeyCode::D).into(),
-            space: is_key_down(KeyCode::Space).into()
+            dir,
+            dash: (clicked || controls.is_down(Action::Dash)).into()
         }
     }
+    /// Returns the direction to move in, no longer than 1.
+    ///
+    /// Digital inputs always have a length of either 0 or 1, while analog sticks can give
+    /// anything in between.
     pub fn dir(&self) -> Vector2 {
-        let Input { w, a, s, d, .. } = *self;
-        Vector2(f64::from(d) - f64::from(a), f64::from(s) - f64::from(w))
+        self.dir
     }
 }
 
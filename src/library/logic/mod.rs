@@ -1,6 +1,20 @@
+pub mod bindings;
+pub use bindings::*;
+pub mod director;
+pub use director::*;
+pub mod events;
+pub use events::*;
+pub mod gamepad;
+pub use gamepad::*;
 pub mod input;
 pub mod spawning;
 pub use input::*;
 pub use spawning::*;
+pub mod practice;
+pub use practice::*;
+pub mod preset;
+pub use preset::*;
 pub mod score_funcs;
 pub use score_funcs::*;
+pub mod stats;
+pub use stats::*;
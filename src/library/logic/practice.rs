@@ -0,0 +1,116 @@
+use std::{fmt, str::FromStr};
+
+use crate::library::TextId;
+
+/// Lowest and highest difficulty a practice run can be locked at.
+pub const PRACTICE_DIFFICULTY: (f64, f64) = (25.00, 500.00);
+
+/// A kind of hazard that can be turned off for practice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hazard {
+    Bullets,
+    /// Lines of bullets following each other.
+    SnakeBullets,
+    Slugs,
+    /// Warnings and the lasers they turn into.
+    Lasers,
+    Flak,
+    /// Bullets flying in from every corner at once.
+    Cross
+}
+
+impl Hazard {
+    pub const COUNT: usize = 6;
+    pub const ALL: [Hazard; Self::COUNT] = [
+        Hazard::Bullets,
+        Hazard::SnakeBullets,
+        Hazard::Slugs,
+        Hazard::Lasers,
+        Hazard::Flak,
+        Hazard::Cross
+    ];
+
+    pub fn text(self) -> TextId {
+        match self {
+            Hazard::Bullets => TextId::HazardBullets,
+            Hazard::SnakeBullets => TextId::HazardSnakes,
+            Hazard::Slugs => TextId::HazardSlugs,
+            Hazard::Lasers => TextId::HazardLasers,
+            Hazard::Flak => TextId::HazardFlak,
+            Hazard::Cross => TextId::HazardCross
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Hazard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Hazard::Bullets => "bullets",
+            Hazard::SnakeBullets => "snakes",
+            Hazard::Slugs => "slugs",
+            Hazard::Lasers => "lasers",
+            Hazard::Flak => "flak",
+            Hazard::Cross => "cross"
+        })
+    }
+}
+
+impl FromStr for Hazard {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Hazard::ALL
+            .into_iter()
+            .find(|hazard| hazard.to_string() == s)
+            .ok_or(())
+    }
+}
+
+/// How practice runs are set up. Practice runs never end up in the high scores.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Practice {
+    hazards:        [bool; Hazard::COUNT],
+    /// Difficulty the run stays at.
+    pub difficulty: f64,
+    /// Whether hits still show, but take no health.
+    pub invincible: bool
+}
+
+impl Default for Practice {
+    fn default() -> Self {
+        Self {
+            hazards:    [true; Hazard::COUNT],
+            difficulty: 100.00,
+            invincible: false
+        }
+    }
+}
+
+impl Practice {
+    pub fn enabled(&self, hazard: Hazard) -> bool {
+        self.hazards[hazard.index()]
+    }
+    pub fn set(&mut self, hazard: Hazard, enabled: bool) {
+        self.hazards[hazard.index()] = enabled;
+    }
+    /// Parses a comma-separated list of enabled hazards, as written by [`Practice::hazard_list`].
+    pub fn set_hazard_list(&mut self, list: &str) {
+        self.hazards = [false; Hazard::COUNT];
+        for hazard in list.split(',').filter_map(|name| name.trim().parse().ok()) {
+            self.set(hazard, true);
+        }
+    }
+    /// Lists the enabled hazards, separated by commas.
+    pub fn hazard_list(&self) -> String {
+        let names: Vec<String> = Hazard::ALL
+            .into_iter()
+            .filter(|&hazard| self.enabled(hazard))
+            .map(|hazard| hazard.to_string())
+            .collect();
+        names.join(", ")
+    }
+}
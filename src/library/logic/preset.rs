@@ -0,0 +1,99 @@
+use std::{fmt, str::FromStr};
+
+use crate::library::TextId;
+
+/// How hard a run is, picked on the title screen. Each preset keeps its own high scores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Preset {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Insane
+}
+
+/// The numbers a [`Preset`] sets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tuning {
+    /// Difficulty a run starts at.
+    pub start_difficulty: f64,
+    /// Difficulty added per tick.
+    pub ramp:             f64,
+    pub max_hp:           i32,
+    /// Factor on how often health packs spawn.
+    pub heal_rate:        f64,
+    /// Dash charge regained per tick, where 1 is a full dash.
+    pub dash_recharge:    f64
+}
+
+impl Preset {
+    pub const COUNT: usize = 4;
+    pub const ALL: [Preset; Self::COUNT] =
+        [Preset::Easy, Preset::Normal, Preset::Hard, Preset::Insane];
+
+    pub fn tuning(self) -> Tuning {
+        match self {
+            Preset::Easy => Tuning {
+                start_difficulty: 60.00,
+                ramp:             0.07,
+                max_hp:           9,
+                heal_rate:        1.50,
+                dash_recharge:    0.0125
+            },
+            Preset::Normal => Tuning {
+                start_difficulty: 100.00,
+                ramp:             0.10,
+                max_hp:           8,
+                heal_rate:        1.00,
+                dash_recharge:    0.01
+            },
+            Preset::Hard => Tuning {
+                start_difficulty: 150.00,
+                ramp:             0.13,
+                max_hp:           6,
+                heal_rate:        0.60,
+                dash_recharge:    0.009
+            },
+            Preset::Insane => Tuning {
+                start_difficulty: 220.00,
+                ramp:             0.18,
+                max_hp:           4,
+                heal_rate:        0.30,
+                dash_recharge:    0.008
+            }
+        }
+    }
+    pub fn text(self) -> TextId {
+        match self {
+            Preset::Easy => TextId::PresetEasy,
+            Preset::Normal => TextId::PresetNormal,
+            Preset::Hard => TextId::PresetHard,
+            Preset::Insane => TextId::PresetInsane
+        }
+    }
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Preset::Easy => "easy",
+            Preset::Normal => "normal",
+            Preset::Hard => "hard",
+            Preset::Insane => "insane"
+        })
+    }
+}
+
+impl FromStr for Preset {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Preset::ALL
+            .into_iter()
+            .find(|preset| preset.to_string() == s)
+            .ok_or(())
+    }
+}
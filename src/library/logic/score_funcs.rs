@@ -11,17 +11,120 @@ pub fn fill_leading_zeroes(text: &mut String, num: i32) {
     write!(text, "{num}").expect("string write cannot fail");
 }
 
+/// Formats ticks as minutes and seconds.
+pub fn format_time(ticks: f64) -> String {
+    let seconds = (ticks / 60.00) as i32;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Points for a cheese before the multiplier.
+pub const CHEESE_POINTS: i32 = 100;
+/// How much each step of a combo adds to the multiplier.
+const COMBO_STEP: f64 = 0.25;
+/// The highest multiplier a combo can build up to.
+const MAX_MULTIPLIER: f64 = 4.00;
+/// Ticks a combo lasts without collecting cheese before it drops a step.
+const COMBO_WINDOW: f64 = 240.00;
+
+/// Returns the score multiplier of a combo of the given length.
+pub fn multiplier(combo: u32) -> f64 {
+    (1.00 + f64::from(combo) * COMBO_STEP).min(MAX_MULTIPLIER)
+}
+
+/// Returns the points for a cheese collected at the given multiplier.
+pub fn cheese_points(multiplier: f64) -> i32 {
+    (f64::from(CHEESE_POINTS) * multiplier).round() as i32
+}
+
+/// Points for a graze before the multiplier.
+pub const GRAZE_POINTS: i32 = 20;
+
+/// Returns the points for a graze at the given multiplier.
+pub fn graze_points(multiplier: f64) -> i32 {
+    (f64::from(GRAZE_POINTS) * multiplier).round() as i32
+}
+
+/// Returns the ticks a combo of the given length lasts before dropping a step. Longer combos
+/// are harder to keep up.
+pub fn combo_window(combo: u32) -> f64 {
+    COMBO_WINDOW / (1.00 + f64::from(combo) * 0.05)
+}
+
+/// Cheese collected in quick succession.
+///
+/// Each cheese extends the combo and refreshes its timer. Once the timer runs out, the combo
+/// drops a step at a time, and taking damage breaks it entirely.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Combo {
+    pub count: u32,
+    /// Ticks left until the combo drops a step.
+    pub timer: f64
+}
+
+impl Combo {
+    pub fn multiplier(&self) -> f64 {
+        multiplier(self.count)
+    }
+    /// Scores a cheese at the current multiplier, then extends the combo.
+    pub fn collect(&mut self) -> i32 {
+        let points = cheese_points(self.multiplier());
+        self.count += 1;
+        self.timer = combo_window(self.count);
+        points
+    }
+    pub fn update(&mut self, dt: f64) {
+        if self.count == 0 {
+            return;
+        }
+        self.timer -= dt;
+        if self.timer <= 0.00 {
+            self.count -= 1;
+            self.timer = combo_window(self.count);
+        }
+    }
+    pub fn reset(&mut self) {
+        *self = Combo::default();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn filled(num: i32) -> String {
+        let mut text = String::new();
+        fill_leading_zeroes(&mut text, num);
+        text
+    }
+
     #[test]
     fn test_fill_leading_zeroes() {
-        assert_eq!(fill_leading_zeroes(0), "00000");
-        assert_eq!(fill_leading_zeroes(1), "00001");
-        assert_eq!(fill_leading_zeroes(10), "00010");
-        assert_eq!(fill_leading_zeroes(100), "00100");
-        assert_eq!(fill_leading_zeroes(1000), "01000");
-        assert_eq!(fill_leading_zeroes(10000), "10000");
+        assert_eq!(filled(0), "00000");
+        assert_eq!(filled(1), "00001");
+        assert_eq!(filled(10), "00010");
+        assert_eq!(filled(100), "00100");
+        assert_eq!(filled(1000), "01000");
+        assert_eq!(filled(10000), "10000");
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(0.00), "0:00");
+        assert_eq!(format_time(60.00 * 75.50), "1:15");
+    }
+
+    #[test]
+    fn test_combo() {
+        let mut combo = Combo::default();
+        assert_eq!(combo.collect(), CHEESE_POINTS);
+        assert_eq!(combo.collect(), 125);
+        assert_eq!(combo.count, 2);
+
+        combo.update(combo_window(2) + 1.00);
+        assert_eq!(combo.count, 1);
+
+        combo.reset();
+        assert_eq!(combo.collect(), CHEESE_POINTS);
+        assert_eq!(cheese_points(multiplier(1000)), 400);
     }
 }
@@ -20,25 +20,35 @@ pub fn num_to_side(num: i32) -> Vector2 {
     }
 }
 
-pub fn spawn_pos_vel(side_buffer: f64, edge_buffer: f64) -> (Vector2, Vector2) {
-    let direction = get_rand_dir();
-    pos_vel(direction, edge_buffer, side_buffer)
+pub fn spawn_pos_vel(rng: &Rng, side_buffer: f64, edge_buffer: f64) -> (Vector2, Vector2) {
+    let direction = rng.rand_dir();
+    pos_vel(rng, direction, edge_buffer, side_buffer)
 }
 
-pub fn spawn_pos_vel_from(side: i32, side_buffer: f64, edge_buffer: f64) -> (Vector2, Vector2) {
+pub fn spawn_pos_vel_from(
+    rng: &Rng,
+    side: i32,
+    side_buffer: f64,
+    edge_buffer: f64
+) -> (Vector2, Vector2) {
     let direction = num_to_side(side);
-    pos_vel(direction, edge_buffer, side_buffer)
+    pos_vel(rng, direction, edge_buffer, side_buffer)
 }
 
-pub fn pos_vel(direction: Vector2, edge_buffer: f64, side_buffer: f64) -> (Vector2, Vector2) {
-    let shift = get_shift(direction, edge_buffer);
+pub fn pos_vel(
+    rng: &Rng,
+    direction: Vector2,
+    edge_buffer: f64,
+    side_buffer: f64
+) -> (Vector2, Vector2) {
+    let shift = get_shift(rng, direction, edge_buffer);
     let buffer = direction * side_buffer;
     let pos = CENTER + direction.mul_per(CENTER) + buffer;
     (pos + shift, direction.negate())
 }
 
-pub fn get_shift(dir: Vector2, edge_buffer: f64) -> Vector2 {
+pub fn get_shift(rng: &Rng, dir: Vector2, edge_buffer: f64) -> Vector2 {
     let rot_dir = dir.rotate_once();
     let shift_range = rot_dir.mul_per(CENTER).len() - edge_buffer;
-    rot_dir * (rand(shift_range * 2.00) - shift_range)
+    rot_dir * (rng.rand(shift_range * 2.00) - shift_range)
 }
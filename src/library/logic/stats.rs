@@ -0,0 +1,201 @@
+//! Statistics gathered over a run.
+//!
+//! Once a run ends, its statistics get exported to a JSON file in [`paths::stats_dir`], one
+//! file per run, for balancing the game.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH}
+};
+
+use crate::library::{Preset, paths};
+
+/// Ticks between samples of the difficulty curve.
+pub const CURVE_INTERVAL: f64 = 60.00;
+
+/// What happened during a run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunStats {
+    pub seed:              u64,
+    pub preset:            Preset,
+    /// Whether this was a practice run, which never counts for high scores.
+    pub practice:          bool,
+    /// Whether a [`Director`](super::Director) adapted the difficulty. Such runs have their
+    /// own high scores and replays.
+    pub adaptive:          bool,
+    pub score:             i32,
+    /// Ticks survived.
+    pub time:              f64,
+    pub cheese:            u32,
+    pub dashes:            u32,
+    /// Damage taken, keyed by the name of the class that dealt it.
+    pub damage:            BTreeMap<&'static str, i32>,
+    /// Health packs picked up.
+    pub heals:             u32,
+    pub peak_difficulty:   f64,
+    /// Smallest gap between a burger and the hit range of a hazard that missed, in pixels.
+    pub closest_call:      Option<f64>,
+    /// Longest combo built up, see [`Combo`](super::Combo).
+    pub best_combo:        u32,
+    pub peak_multiplier:   f64,
+    /// Hazards that passed close by without hitting.
+    pub grazes:            u32,
+    /// Cheese collected since last taking damage.
+    pub clean_streak:      u32,
+    pub best_clean_streak: u32,
+    /// Difficulty over time as `(tick, difficulty)`, sampled while a
+    /// [`Director`](super::Director) adapts it.
+    pub difficulty_curve:  Vec<(f64, f64)>
+}
+
+impl RunStats {
+    pub fn new(seed: u64) -> RunStats {
+        RunStats {
+            seed,
+            ..RunStats::default()
+        }
+    }
+    /// Records the current state of the combo.
+    pub fn track_combo(&mut self, count: u32, multiplier: f64) {
+        self.best_combo = self.best_combo.max(count);
+        self.peak_multiplier = self.peak_multiplier.max(multiplier);
+    }
+    pub fn track_cheese(&mut self) {
+        self.cheese += 1;
+        self.clean_streak += 1;
+        self.best_clean_streak = self.best_clean_streak.max(self.clean_streak);
+    }
+    pub fn track_damage(&mut self, class: &'static str, dmg: i32) {
+        *self.damage.entry(class).or_default() += dmg;
+        self.clean_streak = 0;
+    }
+    /// Records a hazard missing a burger by the given gap.
+    pub fn track_miss(&mut self, gap: f64) {
+        if self.closest_call.is_none_or(|closest| gap < closest) {
+            self.closest_call = Some(gap);
+        }
+    }
+    /// Samples the difficulty into the curve, at most once per [`CURVE_INTERVAL`].
+    pub fn track_curve(&mut self, difficulty: f64) {
+        let due = self
+            .difficulty_curve
+            .last()
+            .is_none_or(|&(time, _)| self.time - time >= CURVE_INTERVAL);
+        if due {
+            self.difficulty_curve.push((self.time, difficulty));
+        }
+    }
+    pub fn total_damage(&self) -> i32 {
+        self.damage.values().sum()
+    }
+    /// Writes the statistics to a new file in [`paths::stats_dir`], returning its path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file cannot be written.
+    pub fn export(&self) -> io::Result<PathBuf> {
+        let dir = paths::stats_dir();
+        fs::create_dir_all(&dir)?;
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_millis());
+        let path = dir.join(format!("run_{stamp}.json"));
+        fs::write(&path, self.to_json())?;
+        Ok(path)
+    }
+
+    fn to_json(&self) -> String {
+        let damage: Vec<String> = self
+            .damage
+            .iter()
+            .map(|(class, dmg)| format!("\"{class}\": {dmg}"))
+            .collect();
+        let closest_call = self
+            .closest_call
+            .map_or_else(|| "null".to_string(), |gap| gap.to_string());
+
+        let curve: Vec<String> = self
+            .difficulty_curve
+            .iter()
+            .map(|(time, difficulty)| format!("[{time:.0}, {difficulty:.2}]"))
+            .collect();
+
+        let mut json = String::from("{\n");
+        let fields = [
+            ("seed", self.seed.to_string()),
+            ("preset", format!("\"{}\"", self.preset)),
+            ("practice", self.practice.to_string()),
+            ("adaptive", self.adaptive.to_string()),
+            ("score", self.score.to_string()),
+            ("time", self.time.to_string()),
+            ("cheese", self.cheese.to_string()),
+            ("dashes", self.dashes.to_string()),
+            ("damage", format!("{{{}}}", damage.join(", "))),
+            ("damage_total", self.total_damage().to_string()),
+            ("heals", self.heals.to_string()),
+            ("peak_difficulty", self.peak_difficulty.to_string()),
+            ("closest_call", closest_call),
+            ("best_combo", self.best_combo.to_string()),
+            ("peak_multiplier", self.peak_multiplier.to_string()),
+            ("grazes", self.grazes.to_string()),
+            ("best_clean_streak", self.best_clean_streak.to_string()),
+            ("difficulty_curve", format!("[{}]", curve.join(", ")))
+        ];
+        for (i, (key, value)) in fields.iter().enumerate() {
+            let comma = if i + 1 < fields.len() { "," } else { "" };
+            writeln!(json, "  \"{key}\": {value}{comma}").expect("string write cannot fail");
+        }
+        json.push('}');
+        json
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_tracking() {
+        let mut stats = RunStats::new(3);
+        stats.track_cheese();
+        stats.track_cheese();
+        stats.track_damage("slug", 7);
+        stats.track_cheese();
+        stats.track_damage("bullet", 3);
+        stats.track_damage("bullet", 3);
+        stats.track_miss(2.00);
+        stats.track_miss(0.50);
+        stats.track_miss(1.00);
+        assert_eq!(stats.total_damage(), 13);
+        assert_eq!(stats.damage.get("slug"), Some(&7));
+        assert_eq!(stats.closest_call, Some(0.50));
+        assert_eq!((stats.cheese, stats.best_clean_streak), (3, 2));
+
+        for tick in 0..150 {
+            stats.time = f64::from(tick);
+            stats.track_curve(100.00);
+        }
+        assert_eq!(stats.difficulty_curve.len(), 3);
+    }
+
+    #[test]
+    fn test_stats_json() {
+        let mut stats = RunStats::new(3);
+        stats.track_damage("slug", 7);
+        let json = stats.to_json();
+        assert!(json.starts_with(concat!(
+            "{\n",
+            "  \"seed\": 3,\n",
+            "  \"preset\": \"normal\",\n",
+            "  \"practice\": false,\n",
+            "  \"adaptive\": false,\n"
+        )));
+        assert!(json.contains("\"damage\": {\"slug\": 7},"));
+        assert!(json.contains("\"closest_call\": null,"));
+        assert!(json.contains("\"best_clean_streak\": 0,"));
+        assert!(json.ends_with("\"difficulty_curve\": []\n}"));
+    }
+}
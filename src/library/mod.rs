@@ -1,3 +1,5 @@
+pub mod achievements;
+pub use achievements::*;
 pub mod assets;
 pub use assets::*;
 pub mod entity;
@@ -12,3 +14,18 @@ pub mod conf;
 pub use conf::*;
 pub mod draw;
 pub use draw::*;
+pub mod highscores;
+pub use highscores::*;
+pub mod lang;
+pub use lang::*;
+pub mod paths;
+pub mod replay;
+pub use replay::*;
+pub mod settings;
+pub use settings::*;
+pub mod text;
+pub use text::*;
+pub mod ui;
+pub use ui::*;
+pub mod viewport;
+pub use viewport::*;
@@ -0,0 +1,127 @@
+//! Resolves where the game reads its assets from and where it writes its files to.
+//!
+//! Loose assets are looked up next to the executable, so the game can be launched from any
+//! working directory. Everything the game writes goes into the platform's data or config
+//! directory, e.g. `~/.local/share/become_cheeseburger` on Linux.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::OnceLock
+};
+
+const APP_DIR: &str = "become_cheeseburger";
+
+/// Returns the directory containing the executable, or the working directory if it is unknown.
+pub fn exe_dir() -> &'static Path {
+    static EXE_DIR: OnceLock<PathBuf> = OnceLock::new();
+    EXE_DIR.get_or_init(|| {
+        env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+            .unwrap_or_default()
+    })
+}
+
+/// Returns the candidate locations of a loose asset, in order of priority.
+///
+/// The path is given relative to the crate root, e.g. `assets/sprites/burger.png`. Debug builds
+/// also look in the crate root itself, since `cargo run` puts the executable under `target/`.
+pub fn asset_candidates(relative: &str) -> Vec<PathBuf> {
+    let mut candidates = vec![exe_dir().join(relative)];
+    if cfg!(debug_assertions) {
+        candidates.push(Path::new(env!("CARGO_MANIFEST_DIR")).join(relative));
+    }
+    candidates
+}
+
+/// Returns the directory for persistent game data such as high scores and replays.
+pub fn data_dir() -> PathBuf {
+    platform_data_dir().join(APP_DIR)
+}
+
+/// Returns the directory for configuration files.
+pub fn config_dir() -> PathBuf {
+    platform_config_dir().join(APP_DIR)
+}
+
+pub fn replays_dir() -> PathBuf {
+    data_dir().join("replays")
+}
+
+/// Returns the directory the statistics of every run are exported to.
+pub fn stats_dir() -> PathBuf {
+    data_dir().join("stats")
+}
+
+/// Returns the directory the high score table of each difficulty preset is kept in.
+pub fn scores_dir() -> PathBuf {
+    data_dir().join("scores")
+}
+
+pub fn settings_file() -> PathBuf {
+    config_dir().join("settings.txt")
+}
+
+#[cfg(target_os = "windows")]
+fn platform_data_dir() -> PathBuf {
+    env_dir("APPDATA").unwrap_or_else(|| exe_dir().to_path_buf())
+}
+
+#[cfg(target_os = "windows")]
+fn platform_config_dir() -> PathBuf {
+    platform_data_dir()
+}
+
+#[cfg(target_os = "macos")]
+fn platform_data_dir() -> PathBuf {
+    home_join("Library/Application Support")
+}
+
+#[cfg(target_os = "macos")]
+fn platform_config_dir() -> PathBuf {
+    platform_data_dir()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn platform_data_dir() -> PathBuf {
+    env_dir("XDG_DATA_HOME").unwrap_or_else(|| home_join(".local/share"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn platform_config_dir() -> PathBuf {
+    env_dir("XDG_CONFIG_HOME").unwrap_or_else(|| home_join(".config"))
+}
+
+/// Reads a directory from an environment variable, ignoring it unless it is an absolute path.
+fn env_dir(var: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+/// Joins the path onto the home directory, falling back to the executable's directory.
+#[cfg(not(target_os = "windows"))]
+fn home_join(relative: &str) -> PathBuf {
+    env_dir("HOME").map_or_else(|| exe_dir().to_path_buf(), |home| home.join(relative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dirs_are_app_specific() {
+        assert!(data_dir().ends_with(APP_DIR));
+        assert!(config_dir().ends_with(APP_DIR));
+        assert!(replays_dir().starts_with(data_dir()));
+        assert!(settings_file().starts_with(config_dir()));
+    }
+
+    #[test]
+    fn test_asset_candidates() {
+        let candidates = asset_candidates("joystix.otf");
+        assert_eq!(candidates[0], exe_dir().join("joystix.otf"));
+        assert!(candidates.iter().any(|path| path.is_file()));
+    }
+}
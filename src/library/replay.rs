@@ -0,0 +1,265 @@
+//! Recorded runs, and racing against the best one.
+//!
+//! Hazards and cheese spawn from a [`Rng`](crate::library::Rng) seeded per run, so runs on the
+//! same seed face the same hazards, apart from lasers aiming at wherever the burgers are. The
+//! best run is kept in [`paths::replays_dir`] and shown as a ghost burger during later runs on
+//! its seed. Runs only race runs on the same [`RaceKey`], so a ghost always played the same
+//! preset with the same number of players, and adaptive difficulty only if the run has it too.
+//! Only the latest seed's best run is kept per preset, number of players and kind of
+//! difficulty, so old seeds don't pile up.
+//!
+//! A replay file starts with `key = value` header lines, followed by one
+//! `time x y score` line per recorded frame. In co-op, the positions of the other living
+//! burgers follow as further `x y` pairs.
+
+use std::{
+    fmt::Write,
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH}
+};
+
+use crate::library::{Preset, Vector2, paths};
+
+/// One recorded moment of a run, `time` being the ticks elapsed since it started.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub time:    f64,
+    /// Position of every living burger, in player order.
+    pub burgers: Vec<Vector2>,
+    pub score:   i32
+}
+
+/// What a run races against: the best run on the same seed, preset and number of players,
+/// with the same kind of difficulty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaceKey {
+    pub seed:     u64,
+    pub preset:   Preset,
+    pub coop:     bool,
+    pub adaptive: bool
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    pub key:    RaceKey,
+    pub frames: Vec<Frame>
+}
+
+impl Replay {
+    pub fn new(key: RaceKey) -> Replay {
+        Replay {
+            key,
+            frames: Vec::new()
+        }
+    }
+    /// Returns the final score of the run.
+    pub fn score(&self) -> i32 {
+        self.frames.last().map_or(0, |f| f.score)
+    }
+    /// Returns the latest frame at or before `time`, or `None` if the run had already ended.
+    pub fn frame_at(&self, time: f64) -> Option<&Frame> {
+        let last = self.frames.last()?;
+        if time > last.time {
+            return None;
+        }
+        let next = self.frames.partition_point(|f| f.time <= time);
+        self.frames.get(next.saturating_sub(1))
+    }
+    /// Returns the file the best run on `key` is kept in. Runs on every seed share it, and
+    /// only the latest seed's best run is kept.
+    pub fn file(key: RaceKey) -> PathBuf {
+        let coop = if key.coop { "_coop" } else { "" };
+        let adaptive = if key.adaptive { "_adaptive" } else { "" };
+        paths::replays_dir().join(format!("best_{}{coop}{adaptive}.txt", key.preset))
+    }
+    /// Loads the best run on `key`, if there is one.
+    pub fn load_best(key: RaceKey) -> Option<Replay> {
+        let text = fs::read_to_string(Replay::file(key)).ok()?;
+        Replay::parse(&text).filter(|replay| replay.key == key)
+    }
+    /// Writes the run as the best one on its key, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file cannot be written.
+    pub fn save_best(&self) -> io::Result<()> {
+        let path = Replay::file(self.key);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_text())
+    }
+
+    fn parse(text: &str) -> Option<Replay> {
+        let mut seed = None;
+        let mut preset = Preset::default();
+        let mut coop = false;
+        let mut adaptive = false;
+        let mut frames = Vec::new();
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if let Some((key, value)) = line.split_once('=') {
+                let value = value.trim();
+                match key.trim() {
+                    "seed" => seed = value.parse().ok(),
+                    "preset" => preset = value.parse().ok()?,
+                    "coop" => coop = value.parse().ok()?,
+                    "adaptive" => adaptive = value.parse().ok()?,
+                    _ => ()
+                }
+                continue;
+            }
+            let fields: Vec<f64> = line
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .ok()?;
+            let [time, x, y, score, others @ ..] = fields.as_slice() else {
+                return None;
+            };
+            let others = others.chunks_exact(2).map(|pair| Vector2(pair[0], pair[1]));
+            frames.push(Frame {
+                time:    *time,
+                burgers: [Vector2(*x, *y)].into_iter().chain(others).collect(),
+                score:   *score as i32
+            });
+        }
+        let key = RaceKey {
+            seed: seed?,
+            preset,
+            coop,
+            adaptive
+        };
+        Some(Replay { key, frames })
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = String::new();
+        let key = self.key;
+        writeln!(text, "seed = {}", key.seed).expect("string write cannot fail");
+        writeln!(text, "preset = {}", key.preset).expect("string write cannot fail");
+        writeln!(text, "coop = {}", key.coop).expect("string write cannot fail");
+        writeln!(text, "adaptive = {}", key.adaptive).expect("string write cannot fail");
+        writeln!(text, "score = {}", self.score()).expect("string write cannot fail");
+        for f in &self.frames {
+            let Some((first, others)) = f.burgers.split_first() else {
+                continue;
+            };
+            write!(text, "{} {} {} {}", f.time, first.x(), first.y(), f.score)
+                .expect("string write cannot fail");
+            for pos in others {
+                write!(text, " {} {}", pos.x(), pos.y()).expect("string write cannot fail");
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Returns a seed that changes once a day, so everyone races the same runs on a given day.
+pub fn daily_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs() / 86_400)
+}
+
+/// The run being recorded, and the best run on the same key to race against.
+pub struct Race {
+    pub time:      f64,
+    pub recording: Replay,
+    pub best:      Option<Replay>
+}
+
+impl Race {
+    /// Loads the ghost for `key`.
+    pub fn start(key: RaceKey) -> Race {
+        Race {
+            time:      0.00,
+            recording: Replay::new(key),
+            best:      Replay::load_best(key)
+        }
+    }
+    /// Advances the clock by `dt` ticks and records the burgers and score.
+    pub fn record(&mut self, dt: f64, burgers: Vec<Vector2>, score: i32) {
+        self.time += dt;
+        self.recording.frames.push(Frame {
+            time: self.time,
+            burgers,
+            score
+        });
+    }
+    /// Returns the ghost's frame at the current time, if it is still alive.
+    pub fn ghost(&self) -> Option<&Frame> {
+        self.best.as_ref()?.frame_at(self.time)
+    }
+    /// Returns how far ahead (or behind, if negative) `score` is of the ghost at this time.
+    pub fn delta(&self, score: i32) -> Option<i32> {
+        let best = self.best.as_ref()?;
+        let ghost_score = best.frame_at(self.time).map_or(best.score(), |f| f.score);
+        Some(score - ghost_score)
+    }
+    /// Saves the recorded run if it beat the ghost, which it then replaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the replay file cannot be written.
+    pub fn finish(&mut self) -> io::Result<()> {
+        let beaten = self
+            .best
+            .as_ref()
+            .is_none_or(|best| self.recording.score() > best.score());
+        if beaten && !self.recording.frames.is_empty() {
+            self.recording.save_best()?;
+            self.best = Some(self.recording.clone());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay() -> Replay {
+        let mut replay = Replay::new(RaceKey {
+            seed:     7,
+            preset:   Preset::Hard,
+            coop:     false,
+            adaptive: true
+        });
+        for i in 0..4 {
+            replay.frames.push(Frame {
+                time:    f64::from(i) + 1.00,
+                burgers: vec![Vector2(f64::from(i), 2.50)],
+                score:   i * 100
+            });
+        }
+        replay
+    }
+
+    #[test]
+    fn test_replay_round_trip() {
+        let replay = replay();
+        assert_eq!(Replay::parse(&replay.to_text()), Some(replay));
+        assert_eq!(Replay::parse("1 2 3 4"), None);
+    }
+
+    #[test]
+    fn test_replay_round_trip_coop() {
+        let mut replay = replay();
+        replay.key.coop = true;
+        for frame in &mut replay.frames {
+            frame.burgers.push(Vector2(-1.00, frame.time));
+        }
+        assert_eq!(Replay::parse(&replay.to_text()), Some(replay));
+    }
+
+    #[test]
+    fn test_replay_frame_at() {
+        let replay = replay();
+        assert_eq!(replay.frame_at(2.50).map(|f| f.score), Some(100));
+        assert_eq!(replay.frame_at(0.50).map(|f| f.score), Some(0));
+        assert_eq!(replay.frame_at(9.00), None);
+        assert_eq!(replay.score(), 300);
+    }
+}
@@ -0,0 +1,294 @@
+//! Player preferences, persisted to [`paths::settings_file`].
+//!
+//! The file holds one `key = value` pair per line. Unknown keys and invalid values are
+//! ignored, falling back to the defaults, so an outdated or hand-edited file never stops the
+//! game from starting.
+
+use std::{fmt::Write, fs, io, str::FromStr};
+
+use crate::library::{
+    Action, Binding, Bindings, ControlScheme, DEFAULT_LANGUAGE, HAZARDS, HAZARDS_COLORBLIND,
+    Hazards, PRACTICE_DIFFICULTY, Practice, Preset, SCALE, Volumes, daily_seed, paths
+};
+
+/// Largest window scale, in window pixels per game pixel.
+pub const MAX_SCALE: u32 = 12;
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    pub volumes:       Volumes,
+    /// Size of a game pixel on screen, in window pixels.
+    pub window_scale:  u32,
+    pub fullscreen:    bool,
+    /// Replaces full-screen flashes with a vignette and slows down strobing warnings.
+    pub reduced_flash: bool,
+    /// Uses hazard colours that don't rely on telling red and green apart.
+    pub colorblind:    bool,
+    pub bindings:      Bindings,
+    pub controls:      ControlScheme,
+    /// Name of the string table to show text from, see [`Strings`].
+    pub language:      String,
+    /// Whether a second player joins on the gamepad, while the first uses the keyboard.
+    pub coop:          bool,
+    /// Ticks for which a dash press is remembered until the dash is ready.
+    pub dash_buffer:   f64,
+    /// Whether dashing without holding a direction dashes in the last direction moved in.
+    pub dash_last_dir: bool,
+    /// Seed every run starts from, or `None` for the daily seed.
+    pub seed:          Option<u64>,
+    /// The difficulty preset last picked on the title screen.
+    pub preset:        Preset,
+    /// Whether a [`Director`](crate::library::Director) adapts the difficulty to the players.
+    pub adaptive:      bool,
+    /// How practice runs are set up.
+    pub practice:      Practice
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            volumes:       Volumes::default(),
+            window_scale:  SCALE,
+            fullscreen:    false,
+            reduced_flash: false,
+            colorblind:    false,
+            bindings:      Bindings::default(),
+            controls:      ControlScheme::Keys,
+            language:      DEFAULT_LANGUAGE.to_string(),
+            coop:          false,
+            dash_buffer:   6.00,
+            dash_last_dir: true,
+            seed:          None,
+            preset:        Preset::Normal,
+            adaptive:      false,
+            practice:      Practice::default()
+        }
+    }
+}
+
+impl Settings {
+    /// Returns the number of players in a run.
+    pub fn players(&self) -> usize {
+        if self.coop { 2 } else { 1 }
+    }
+    /// Returns the hazard colours to draw with.
+    pub fn hazards(&self) -> &'static Hazards {
+        if self.colorblind {
+            &HAZARDS_COLORBLIND
+        } else {
+            &HAZARDS
+        }
+    }
+    /// Returns the seed the next run starts from.
+    pub fn run_seed(&self) -> u64 {
+        self.seed.unwrap_or_else(daily_seed)
+    }
+    /// Loads the settings file, using the defaults for anything missing.
+    pub fn load() -> Settings {
+        fs::read_to_string(paths::settings_file())
+            .map(|text| Settings::parse(&text))
+            .unwrap_or_default()
+    }
+    /// Writes the settings file, creating its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let path = paths::settings_file();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_text())
+    }
+
+    fn parse(text: &str) -> Settings {
+        let mut settings = Settings::default();
+        let pairs = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()));
+
+        for (key, value) in pairs {
+            match key {
+                "volume.master" => parse_clamped(value, &mut settings.volumes.master, 0.00, 1.00),
+                "volume.music" => parse_clamped(value, &mut settings.volumes.music, 0.00, 1.00),
+                "volume.sfx" => parse_clamped(value, &mut settings.volumes.sfx, 0.00, 1.00),
+                "window.scale" => parse_clamped(value, &mut settings.window_scale, 1, MAX_SCALE),
+                "window.fullscreen" => parse_into(value, &mut settings.fullscreen),
+                "display.reduced_flash" => parse_into(value, &mut settings.reduced_flash),
+                // written before flashes were reduced instead of turned off
+                "screen_flash" => {
+                    if let Ok(flash) = value.parse::<bool>() {
+                        settings.reduced_flash = !flash;
+                    }
+                }
+                "display.colorblind" => parse_into(value, &mut settings.colorblind),
+                "controls.scheme" => parse_into(value, &mut settings.controls),
+                "language" => settings.language = value.to_string(),
+                "coop" => parse_into(value, &mut settings.coop),
+                "dash.buffer" => parse_into(value, &mut settings.dash_buffer),
+                "dash.last_direction" => parse_into(value, &mut settings.dash_last_dir),
+                "run.seed" => settings.seed = value.parse().ok(),
+                "difficulty.preset" => parse_into(value, &mut settings.preset),
+                "difficulty.adaptive" => parse_into(value, &mut settings.adaptive),
+                "practice.hazards" => settings.practice.set_hazard_list(value),
+                "practice.difficulty" => {
+                    let (min, max) = PRACTICE_DIFFICULTY;
+                    parse_clamped(value, &mut settings.practice.difficulty, min, max);
+                }
+                "practice.invincible" => parse_into(value, &mut settings.practice.invincible),
+                _ => ()
+            }
+            if let Some(name) = key.strip_prefix("bind.")
+                && let Some(action) = Action::ALL.into_iter().find(|a| a.name() == name)
+            {
+                let bindings = value.split(',').filter_map(|b| Binding::parse(b.trim()));
+                settings.bindings.set(action, bindings.collect());
+            }
+        }
+        settings
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = String::new();
+        let volumes = self.volumes;
+        writeln!(text, "volume.master = {}", volumes.master).expect("string write cannot fail");
+        writeln!(text, "volume.music = {}", volumes.music).expect("string write cannot fail");
+        writeln!(text, "volume.sfx = {}", volumes.sfx).expect("string write cannot fail");
+        writeln!(text, "window.scale = {}", self.window_scale).expect("string write cannot fail");
+        writeln!(text, "window.fullscreen = {}", self.fullscreen)
+            .expect("string write cannot fail");
+        writeln!(text, "display.reduced_flash = {}", self.reduced_flash)
+            .expect("string write cannot fail");
+        writeln!(text, "display.colorblind = {}", self.colorblind)
+            .expect("string write cannot fail");
+        writeln!(text, "controls.scheme = {}", self.controls).expect("string write cannot fail");
+        writeln!(text, "language = {}", self.language).expect("string write cannot fail");
+        writeln!(text, "coop = {}", self.coop).expect("string write cannot fail");
+        writeln!(text, "dash.buffer = {}", self.dash_buffer).expect("string write cannot fail");
+        writeln!(text, "dash.last_direction = {}", self.dash_last_dir)
+            .expect("string write cannot fail");
+        match self.seed {
+            Some(seed) => writeln!(text, "run.seed = {seed}"),
+            None => writeln!(text, "run.seed = daily")
+        }
+        .expect("string write cannot fail");
+        writeln!(text, "difficulty.preset = {}", self.preset).expect("string write cannot fail");
+        writeln!(text, "difficulty.adaptive = {}", self.adaptive)
+            .expect("string write cannot fail");
+        let practice = self.practice;
+        writeln!(text, "practice.hazards = {}", practice.hazard_list())
+            .expect("string write cannot fail");
+        writeln!(text, "practice.difficulty = {}", practice.difficulty)
+            .expect("string write cannot fail");
+        writeln!(text, "practice.invincible = {}", practice.invincible)
+            .expect("string write cannot fail");
+        for action in Action::ALL {
+            let names: Vec<_> = self.bindings.get(action).iter().map(|b| b.name()).collect();
+            writeln!(text, "bind.{} = {}", action.name(), names.join(", "))
+                .expect("string write cannot fail");
+        }
+        text
+    }
+}
+
+/// Overwrites the target with the parsed value, leaving it untouched if parsing fails.
+fn parse_into<T: FromStr>(value: &str, target: &mut T) {
+    if let Ok(parsed) = value.parse() {
+        *target = parsed;
+    }
+}
+
+/// Like [`parse_into`], but clamps the parsed value to `min..=max`. Values that cannot be
+/// compared, like NaN, are ignored.
+fn parse_clamped<T: FromStr + PartialOrd>(value: &str, target: &mut T, min: T, max: T) {
+    let Ok(parsed) = value.parse::<T>() else {
+        return;
+    };
+    if parsed < min {
+        *target = min;
+    } else if parsed > max {
+        *target = max;
+    } else if parsed >= min {
+        *target = parsed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use macroquad::input::KeyCode;
+
+    use super::*;
+    use crate::library::Hazard;
+
+    #[test]
+    fn test_settings_round_trip() {
+        let mut settings = Settings::default();
+        settings
+            .bindings
+            .rebind(Action::Dash, 0, Binding::Key(KeyCode::LeftShift));
+        settings.dash_buffer = 10.00;
+        settings.dash_last_dir = false;
+        settings.controls = ControlScheme::Mouse;
+        settings.coop = true;
+        settings.language = "de".to_string();
+        settings.seed = Some(42);
+        settings.preset = Preset::Insane;
+        settings.adaptive = true;
+        settings.practice.set(Hazard::Flak, false);
+        settings.practice.difficulty = 250.00;
+        settings.practice.invincible = true;
+        settings.volumes.music = 0.30;
+        settings.window_scale = 3;
+        settings.fullscreen = true;
+        settings.reduced_flash = true;
+        settings.colorblind = true;
+        assert_eq!(Settings::parse(&settings.to_text()), settings);
+    }
+
+    #[test]
+    fn test_settings_ignore_garbage() {
+        let settings = Settings::parse(
+            "# comment\nnonsense\nbind.jump = Space\nbind.up = Nope, I\ndash.buffer = x"
+        );
+        let mut expected = Settings::default();
+        expected
+            .bindings
+            .set(Action::Up, vec![Binding::Key(KeyCode::I)]);
+        assert_eq!(settings, expected);
+    }
+
+    #[test]
+    fn test_settings_migrate_screen_flash() {
+        assert!(Settings::parse("screen_flash = false").reduced_flash);
+        assert!(!Settings::parse("screen_flash = true").reduced_flash);
+    }
+
+    #[test]
+    fn test_settings_clamp_out_of_range() {
+        let settings = Settings::parse(
+            "volume.master = 3\nvolume.music = NaN\nvolume.sfx = -1\nwindow.scale = 0"
+        );
+        let expected = Volumes {
+            master: 1.00,
+            sfx: 0.00,
+            ..Volumes::default()
+        };
+        assert_eq!(settings.volumes, expected);
+        assert_eq!(settings.window_scale, 1);
+        assert_eq!(
+            Settings::parse("window.scale = 900").window_scale,
+            MAX_SCALE
+        );
+        let mut expected = Practice::default();
+        expected.difficulty = PRACTICE_DIFFICULTY.1;
+        assert_eq!(
+            Settings::parse("practice.difficulty = 1e9").practice,
+            expected
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Measuring and laying out text on the canvas.
+//!
+//! Positions are rounded to whole canvas pixels, so the pixel font never lands between them.
+
+use crate::*;
+
+/// Vertical spacing between lines of text, in pixels.
+pub const LINE_HEIGHT: f32 = 11.00;
+
+/// Where a line sits relative to the x position it is drawn at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right
+}
+
+impl Align {
+    /// Returns the left edge of a line of the given width, anchored at `x`.
+    pub fn left_edge(self, x: f32, width: f32) -> f32 {
+        let left = match self {
+            Align::Left => x,
+            Align::Center => x - width * 0.50,
+            Align::Right => x - width
+        };
+        left.round()
+    }
+}
+
+/// Returns the width of a line of text, in pixels.
+pub fn measure(text: &str, params: &TextParams) -> f32 {
+    measure_text(text, params.font, params.font_size, params.font_scale).width
+}
+
+/// Draws a line of text anchored at `x`, with `y` being its baseline.
+pub fn draw_text_aligned(text: &str, x: f32, y: f32, align: Align, params: &TextParams) {
+    let left = align.left_edge(x, measure(text, params));
+    draw_text_ex(text, left, y.round(), params.clone());
+}
+
+/// Splits text into lines no wider than `max_width`, breaking between words.
+///
+/// Line breaks in the text are kept, and words too long for a line of their own are broken
+/// between characters.
+pub fn wrap(text: &str, max_width: f32, params: &TextParams) -> Vec<String> {
+    wrap_with(text, max_width, |line| measure(line, params))
+}
+
+fn wrap_with(text: &str, max_width: f32, measure: impl Fn(&str) -> f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{line} {word}")
+            };
+            if measure(&candidate) <= max_width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for c in word.chars() {
+                line.push(c);
+                if measure(&line) > max_width && line.chars().count() > 1 {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, c.to_string()));
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// A block of text wrapped within a box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextBox {
+    pub x:           f32,
+    /// Baseline of the first line.
+    pub y:           f32,
+    pub width:       f32,
+    pub align:       Align,
+    pub line_height: f32
+}
+
+impl TextBox {
+    /// Creates a left-aligned box starting at the given position.
+    pub fn new(x: f32, y: f32, width: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            align: Align::Left,
+            line_height: LINE_HEIGHT
+        }
+    }
+    /// Creates a box as wide as the screen, with its lines centred.
+    pub fn centered(y: f32) -> Self {
+        Self::new(0.00, y, SCREEN_X as f32).align(Align::Center)
+    }
+    #[must_use]
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+    #[must_use]
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+    /// Draws the text wrapped to the box, returning the baseline the next line would go on.
+    pub fn draw(&self, text: &str, params: &TextParams) -> f32 {
+        let anchor = match self.align {
+            Align::Left => self.x,
+            Align::Center => self.x + self.width * 0.50,
+            Align::Right => self.x + self.width
+        };
+        let mut y = self.y;
+        for line in wrap(text, self.width, params) {
+            draw_text_aligned(&line, anchor, y, self.align, params);
+            y += self.line_height;
+        }
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrap_chars(text: &str, max: usize) -> Vec<String> {
+        wrap_with(text, max as f32, |line| line.chars().count() as f32)
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(
+            wrap_chars("you did not become", 11),
+            ["you did not", "become"]
+        );
+        assert_eq!(wrap_chars("a\nb c", 10), ["a", "b c"]);
+        assert_eq!(wrap_chars("cheeseburger", 5), ["chees", "eburg", "er"]);
+        assert_eq!(wrap_chars("größe: 8x", 6), ["größe:", "8x"]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_align() {
+        assert_eq!(Align::Left.left_edge(10.00, 20.00), 10.00);
+        assert_eq!(Align::Center.left_edge(10.00, 5.00), 8.00);
+        assert_eq!(Align::Right.left_edge(10.00, 20.00), -10.00);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::*;
+
+/// Lists every achievement, with the description and unlock date of the selected one below.
+pub struct AchievementsScreen {
+    menu: Menu
+}
+
+impl Default for AchievementsScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AchievementsScreen {
+    pub fn new() -> Self {
+        Self {
+            menu: Menu::new(AchievementId::COUNT)
+        }
+    }
+    /// Updates the screen, returning `true` once the player leaves it.
+    pub fn update(&mut self, controls: Controls) -> bool {
+        matches!(self.menu.update(controls), MenuEvent::Back)
+    }
+    pub fn draw(&self, achievements: &Achievements, strings: &Strings, params: &TextParams) {
+        dim_screen();
+        draw_title(strings.get(TextId::Achievements), params);
+
+        let names: Vec<&str> = AchievementId::ALL
+            .iter()
+            .map(|id| strings.get(id.text().0))
+            .collect();
+        self.menu.draw(&names, 22.00, params);
+
+        let selected = AchievementId::ALL[self.menu.selected];
+        let unlocked = achievements.unlocked(selected).map_or_else(
+            || strings.get(TextId::Locked).to_string(),
+            |date| strings.fmt(TextId::UnlockedOn, &[date])
+        );
+        let text = format!("{}\n{unlocked}", strings.get(selected.text().1));
+        TextBox::new(4.00, 90.00, SCREEN_X as f32 - 8.00).draw(&text, params);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::*;
+
+/// Lines of statistics that fit between the title and the restart prompt.
+const PAGE_LINES: usize = 7;
+/// Seconds each page of statistics is shown for, if they don't fit on one.
+const PAGE_TIME: f64 = 4.00;
+
+/// Draws the game over message, the statistics of the run and how to restart. Statistics that
+/// don't fit take turns with the others.
+pub fn draw_game_over(
+    stats: &RunStats,
+    bindings: &Bindings,
+    strings: &Strings,
+    params: &TextParams
+) {
+    dim_screen();
+
+    let title = TextBox::centered(10.00);
+    let y = title.draw(strings.get(TextId::GameOver), params);
+
+    let lines = stat_lines(stats, strings);
+    let pages: Vec<_> = lines.chunks(PAGE_LINES).collect();
+    let page = usize::try_from((get_time() / PAGE_TIME) as i64).unwrap_or_default() % pages.len();
+    TextBox::new(4.00, y + 2.00, SCREEN_X as f32 - 8.00).draw(&pages[page].join("\n"), params);
+
+    let restart_key = bindings
+        .primary_key(Action::Restart)
+        .map_or_else(String::new, Binding::label);
+    let restart = strings.fmt(TextId::RestartPrompt, &[&restart_key]);
+    draw_text_aligned(
+        &restart,
+        CENTER_X as f32,
+        SCREEN_Y as f32 - 6.00,
+        Align::Center,
+        params
+    );
+}
+
+/// Lists the statistics, with the damage taken followed by a line per class that dealt any.
+fn stat_lines(stats: &RunStats, strings: &Strings) -> Vec<String> {
+    let damage = stats.total_damage().to_string();
+    let closest = stats
+        .closest_call
+        .map_or_else(|| "-".to_string(), |gap| format!("{gap:.1}"));
+    let mut lines = vec![
+        strings.fmt(TextId::StatTime, &[&format_time(stats.time)]),
+        strings.fmt(TextId::StatCheese, &[&stats.cheese.to_string()]),
+        strings.fmt(TextId::StatDashes, &[&stats.dashes.to_string()]),
+        strings.fmt(TextId::StatDamage, &[&damage]),
+    ];
+    for (&class, dmg) in &stats.damage {
+        let name = TextId::class(class).map_or(class, |class| strings.get(class));
+        lines.push(strings.fmt(TextId::StatDamageClass, &[name, &dmg.to_string()]));
+    }
+    lines.extend([
+        strings.fmt(TextId::StatHeals, &[&stats.heals.to_string()]),
+        strings.fmt(
+            TextId::StatDifficulty,
+            &[&format!("{:.0}", stats.peak_difficulty)]
+        ),
+        strings.fmt(TextId::StatClosest, &[&closest])
+    ]);
+    lines
+}
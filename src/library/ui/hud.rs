@@ -0,0 +1,274 @@
+//! The heads-up display drawn over the game.
+//!
+//! What goes where is described by a [`HudLayout`], a list of widgets each pinned to a corner
+//! or edge of the screen. [`Hud`] keeps the layout and the animated values between frames, so
+//! the health bars drain and the score counts up instead of jumping.
+
+use crate::*;
+
+/// Width of one hit point on a health bar, in pixels.
+const HP_SEGMENT: i32 = 8;
+const HP_HEIGHT: i32 = 4;
+/// Width of a full dash bar, in pixels.
+const DASH_WIDTH: f64 = 64.00;
+const DASH_HEIGHT: i32 = 2;
+
+/// Hit points per tick the drained part of a health bar catches up with.
+const HP_DRAIN: f64 = 0.08;
+/// Share of the remaining difference the shown score catches up with per tick.
+const SCORE_COUNT: f64 = 0.20;
+
+const HP_BACK: Color = color_u8!(155, 155, 155, 255);
+const HP_FILL: Color = color_u8!(255, 105, 105, 255);
+const HP_DRAINED: Color = color_u8!(255, 215, 215, 255);
+const DASH_READY: Color = color_u8!(255, 255, 255, 255);
+const DASH_CHARGING: Color = color_u8!(55, 155, 255, 255);
+const AHEAD: Color = color_u8!(105, 255, 105, 255);
+const BEHIND: Color = color_u8!(255, 105, 105, 255);
+
+/// The point of the screen a widget is pinned to. The widget's offset points inwards from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    BottomLeft,
+    BottomRight
+}
+
+impl Anchor {
+    /// Returns the top left corner of a box of the given size, offset inwards from the anchor.
+    fn place(self, (dx, dy): (f32, f32), (w, h): (f32, f32)) -> (f32, f32) {
+        let (sx, sy) = (SCREEN_X as f32, SCREEN_Y as f32);
+        match self {
+            Anchor::TopLeft => (dx, dy),
+            Anchor::Top => (((sx - w) * 0.50).round() + dx, dy),
+            Anchor::TopRight => (sx - dx - w, dy),
+            Anchor::BottomLeft => (dx, sy - dy - h),
+            Anchor::BottomRight => (sx - dx - w, sy - dy - h)
+        }
+    }
+    fn align(self) -> Align {
+        match self {
+            Anchor::TopLeft | Anchor::BottomLeft => Align::Left,
+            Anchor::Top => Align::Center,
+            Anchor::TopRight | Anchor::BottomRight => Align::Right
+        }
+    }
+}
+
+/// Something the HUD can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Widget {
+    Score,
+    /// How far ahead of the ghost the score is, see [`Race`].
+    RaceDelta,
+    /// Time survived in the current run.
+    Timer,
+    /// The current score multiplier, hidden while it is at 1x.
+    Multiplier,
+    /// Health bar of the player with the given index.
+    Health(usize),
+    /// Dash bar of the player with the given index.
+    Dash(usize)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HudItem {
+    pub widget: Widget,
+    pub anchor: Anchor,
+    /// Distance from the anchor, pointing inwards. For text, the vertical offset is its
+    /// baseline.
+    pub offset: (f32, f32)
+}
+
+/// Where each widget goes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HudLayout(pub Vec<HudItem>);
+
+impl HudLayout {
+    /// Returns the default layout for the given number of players. The first player's bars sit
+    /// in the bottom left corner, the second player's in the bottom right one.
+    pub fn standard(players: usize) -> HudLayout {
+        let item = |widget, anchor, offset| HudItem {
+            widget,
+            anchor,
+            offset
+        };
+        let mut items = vec![
+            item(Widget::Score, Anchor::TopLeft, (1.00, 9.00)),
+            item(Widget::RaceDelta, Anchor::TopLeft, (48.00, 9.00)),
+            item(Widget::Timer, Anchor::TopRight, (1.00, 9.00)),
+            item(Widget::Multiplier, Anchor::TopRight, (1.00, 20.00)),
+        ];
+        let corners = [Anchor::BottomLeft, Anchor::BottomRight];
+        for (i, corner) in corners.into_iter().enumerate().take(players) {
+            items.push(item(Widget::Health(i), corner, (2.00, 2.00)));
+            items.push(item(Widget::Dash(i), corner, (2.00, 6.00)));
+        }
+        HudLayout(items)
+    }
+}
+
+/// Everything the HUD shows this frame.
+pub struct HudFrame<'a> {
+    pub score:      i32,
+    /// Ticks since the run started.
+    pub time:       f64,
+    pub delta:      Option<i32>,
+    pub multiplier: f64,
+    pub burgers:    &'a [Player]
+}
+
+/// The HUD layout and animated values, carried between frames.
+#[derive(Debug, Default)]
+pub struct Hud {
+    /// The standard layout for `players` players, rebuilt when the number changes.
+    layout:      HudLayout,
+    players:     usize,
+    shown_score: f64,
+    score_text:  String,
+    shown_hp:    Vec<f64>
+}
+
+impl Hud {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Moves the animated values towards the frame's.
+    ///
+    /// Values that go the other way, such as healing or the score resetting, snap instead.
+    pub fn update(&mut self, frame: &HudFrame, dt: f64) {
+        if frame.burgers.len() != self.players {
+            self.players = frame.burgers.len();
+            self.layout = HudLayout::standard(self.players);
+        }
+        let score = f64::from(frame.score);
+        if score < self.shown_score || self.score_text.is_empty() {
+            self.shown_score = score;
+        } else {
+            let step = ((score - self.shown_score) * SCORE_COUNT * dt).max(dt);
+            self.shown_score = (self.shown_score + step).min(score);
+        }
+        self.score_text.clear();
+        fill_leading_zeroes(&mut self.score_text, self.shown_score as i32);
+
+        self.shown_hp.resize(frame.burgers.len(), 0.00);
+        for (shown, burger) in self.shown_hp.iter_mut().zip(frame.burgers) {
+            let hp = f64::from(burger.hp.max(0));
+            *shown = if hp >= *shown {
+                hp
+            } else {
+                (*shown - HP_DRAIN * dt).max(hp)
+            };
+        }
+    }
+    pub fn draw(&self, frame: &HudFrame, params: &TextParams) {
+        for item in &self.layout.0 {
+            match item.widget {
+                Widget::Score => text(item, &self.score_text, params),
+                Widget::RaceDelta => {
+                    if let Some(delta) = frame.delta {
+                        let color = if delta >= 0 { AHEAD } else { BEHIND };
+                        let params = TextParams {
+                            color,
+                            ..params.clone()
+                        };
+                        text(item, &format!("{delta:+}"), &params);
+                    }
+                }
+                Widget::Timer => text(item, &format_time(frame.time), params),
+                Widget::Multiplier => {
+                    if frame.multiplier > 1.00 {
+                        text(item, &format!("x{:.1}", frame.multiplier), params);
+                    }
+                }
+                Widget::Health(i) => {
+                    if let (Some(burger), Some(&shown)) =
+                        (frame.burgers.get(i), self.shown_hp.get(i))
+                    {
+                        health_bar(item, burger, shown);
+                    }
+                }
+                Widget::Dash(i) => {
+                    if let Some(burger) = frame.burgers.get(i) {
+                        dash_bar(item, burger);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn text(item: &HudItem, text: &str, params: &TextParams) {
+    let (x, y) = item.anchor.place(item.offset, (0.00, 0.00));
+    draw_text_aligned(text, x, y, item.anchor.align(), params);
+}
+
+fn health_bar(item: &HudItem, burger: &Player, shown_hp: f64) {
+    let width = burger.max_hp * HP_SEGMENT;
+    let (x, y) = item
+        .anchor
+        .place(item.offset, (width as f32, HP_HEIGHT as f32));
+    let pos = Vector2(f64::from(x), f64::from(y));
+    draw::rec_top_left(pos, width, HP_HEIGHT, HP_BACK);
+    draw::rec_top_left(
+        pos,
+        (shown_hp * f64::from(HP_SEGMENT)) as i32,
+        HP_HEIGHT,
+        HP_DRAINED
+    );
+    draw::rec_top_left(pos, burger.hp.max(0) * HP_SEGMENT, HP_HEIGHT, HP_FILL);
+}
+
+fn dash_bar(item: &HudItem, burger: &Player) {
+    let (x, y) = item
+        .anchor
+        .place(item.offset, (DASH_WIDTH as f32, DASH_HEIGHT as f32));
+    let color = if burger.can_dash() {
+        DASH_READY
+    } else {
+        DASH_CHARGING
+    };
+    draw::rec_top_left(
+        Vector2(f64::from(x), f64::from(y)),
+        (burger.dash_charge * DASH_WIDTH) as i32,
+        DASH_HEIGHT,
+        color
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(score: i32, burgers: &[Player]) -> HudFrame<'_> {
+        HudFrame {
+            score,
+            time: 0.00,
+            delta: None,
+            multiplier: 1.00,
+            burgers
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_hud_animates() {
+        let mut burgers = vec![Player::new(CENTER, 8)];
+        let mut hud = Hud::new();
+        hud.update(&frame(0, &burgers), 1.00);
+        assert_eq!(hud.layout, HudLayout::standard(1));
+
+        burgers[0].hp -= 2;
+        hud.update(&frame(500, &burgers), 1.00);
+        assert!(hud.shown_score > 0.00 && hud.shown_score < 500.00);
+        let drained = hud.shown_hp[0];
+        assert!(drained < 8.00 && drained > 6.00);
+
+        burgers[0].hp = 8;
+        hud.update(&frame(0, &burgers), 1.00);
+        assert_eq!(hud.shown_score, 0.00);
+        assert_eq!(hud.shown_hp[0], 8.00);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::*;
+
+/// What happened in a [`Menu`] this frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuEvent {
+    None,
+    Select(usize),
+    Back
+}
+
+/// A vertical list of items, navigated with the movement actions and picked with dash.
+pub struct Menu {
+    pub selected: usize,
+    len:          usize
+}
+
+impl Menu {
+    pub fn new(len: usize) -> Self {
+        Self { selected: 0, len }
+    }
+    pub fn update(&mut self, controls: Controls) -> MenuEvent {
+        if controls.is_pressed(Action::Up) {
+            self.selected = (self.selected + self.len - 1) % self.len;
+        }
+        if controls.is_pressed(Action::Down) {
+            self.selected = (self.selected + 1) % self.len;
+        }
+        if controls.is_pressed(Action::Dash) {
+            MenuEvent::Select(self.selected)
+        } else if controls.is_pressed(Action::Pause) {
+            MenuEvent::Back
+        } else {
+            MenuEvent::None
+        }
+    }
+    /// Draws the items from the given height downwards, marking the selected one.
+    pub fn draw(&self, items: &[&str], top: f32, params: &TextParams) {
+        for (i, item) in items.iter().enumerate() {
+            let y = top + LINE_HEIGHT * i as f32;
+            if i == self.selected {
+                draw_text_ex(">", 0.00, y, params.clone());
+            }
+            draw_text_ex(item, 8.00, y, params.clone());
+        }
+    }
+}
+
+/// Draws the title of a menu screen, centred at the top.
+pub fn draw_title(title: &str, params: &TextParams) {
+    draw_text_aligned(title, CENTER_X as f32, 10.00, Align::Center, params);
+}
+
+/// Darkens whatever has been drawn so far, so menus stand out on top of the game.
+pub fn dim_screen() {
+    draw_rectangle(
+        0.00,
+        0.00,
+        SCREEN_X as f32,
+        SCREEN_Y as f32,
+        Color::new(0.00, 0.00, 0.00, 0.75)
+    );
+}
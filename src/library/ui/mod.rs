@@ -0,0 +1,18 @@
+pub mod achievements_screen;
+pub use achievements_screen::*;
+pub mod game_over;
+pub use game_over::*;
+pub mod hud;
+pub use hud::*;
+pub mod menu;
+pub use menu::*;
+pub mod practice_screen;
+pub use practice_screen::*;
+pub mod rebind;
+pub use rebind::*;
+pub mod settings_screen;
+pub use settings_screen::*;
+pub mod title_screen;
+pub use title_screen::*;
+pub mod toast;
+pub use toast::*;
@@ -0,0 +1,84 @@
+use crate::*;
+
+/// How much a single press changes the locked difficulty.
+const DIFFICULTY_STEP: f64 = 25.00;
+
+/// Entries of the screen after the hazard toggles, in order.
+const DIFFICULTY: usize = Hazard::COUNT;
+const INVINCIBLE: usize = Hazard::COUNT + 1;
+const START: usize = Hazard::COUNT + 2;
+
+/// What happened on the [`PracticeScreen`] this frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PracticeEvent {
+    None,
+    Start,
+    Back
+}
+
+/// Sets up a practice run: which hazards spawn, the difficulty it stays at, and invincibility.
+pub struct PracticeScreen {
+    menu: Menu
+}
+
+impl Default for PracticeScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PracticeScreen {
+    pub fn new() -> Self {
+        Self {
+            menu: Menu::new(START + 1)
+        }
+    }
+    /// Updates the screen, changing the practice setup in the settings.
+    pub fn update(&mut self, settings: &mut Settings, gamepad: &Gamepad) -> PracticeEvent {
+        let controls = Controls::new(&settings.bindings, gamepad);
+        let step = match self.menu.update(controls) {
+            MenuEvent::Select(START) => return PracticeEvent::Start,
+            MenuEvent::Back => return PracticeEvent::Back,
+            MenuEvent::Select(_) => 1.00,
+            MenuEvent::None if controls.is_pressed(Action::Right) => 1.00,
+            MenuEvent::None if controls.is_pressed(Action::Left) => -1.00,
+            MenuEvent::None => return PracticeEvent::None
+        };
+
+        let practice = &mut settings.practice;
+        match self.menu.selected {
+            DIFFICULTY => {
+                let (min, max) = PRACTICE_DIFFICULTY;
+                practice.difficulty =
+                    (practice.difficulty + step * DIFFICULTY_STEP).clamp(min, max);
+            }
+            INVINCIBLE => practice.invincible = !practice.invincible,
+            i => {
+                if let Some(&hazard) = Hazard::ALL.get(i) {
+                    practice.set(hazard, !practice.enabled(hazard));
+                }
+            }
+        }
+        PracticeEvent::None
+    }
+    pub fn draw(&self, settings: &Settings, strings: &Strings, params: &TextParams) {
+        dim_screen();
+        draw_title(strings.get(TextId::Practice), params);
+
+        let practice = &settings.practice;
+        let on_off = |value| strings.get(TextId::on_off(value));
+        let mut items: Vec<String> = Hazard::ALL
+            .into_iter()
+            .map(|hazard| strings.fmt(hazard.text(), &[on_off(practice.enabled(hazard))]))
+            .collect();
+        items.push(strings.fmt(
+            TextId::PracticeDifficulty,
+            &[&format!("{:.0}", practice.difficulty)]
+        ));
+        items.push(strings.fmt(TextId::Invincible, &[on_off(practice.invincible)]));
+        items.push(strings.get(TextId::Start).to_string());
+
+        let items: Vec<&str> = items.iter().map(String::as_str).collect();
+        self.menu.draw(&items, 20.00, params);
+    }
+}
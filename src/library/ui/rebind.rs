@@ -0,0 +1,113 @@
+use crate::*;
+
+/// Lists every action with its primary key and button. Left and right step through all the
+/// bindings of the selected action, and the empty slot after them, which then gets rebound to
+/// the next key or button pressed. See [`Bindings::rebind`] for which changes are allowed.
+pub struct RebindScreen {
+    menu:    Menu,
+    /// Binding of the selected action to change, past the last one to add a new binding.
+    slot:    usize,
+    waiting: Option<Action>
+}
+
+impl Default for RebindScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Entries after the actions.
+const DEFAULTS: usize = Action::COUNT;
+const BACK: usize = Action::COUNT + 1;
+
+/// Keys that cancel and remove while waiting for a new binding. Neither can be bound.
+const CANCEL: KeyCode = KeyCode::Backspace;
+const REMOVE: KeyCode = KeyCode::Delete;
+
+impl RebindScreen {
+    pub fn new() -> Self {
+        Self {
+            menu:    Menu::new(Action::COUNT + 2),
+            slot:    0,
+            waiting: None
+        }
+    }
+    /// Updates the screen, returning `true` once the player leaves it.
+    ///
+    /// While waiting for a new binding, backspace cancels and delete removes the binding
+    /// instead.
+    pub fn update(&mut self, bindings: &mut Bindings, gamepad: &Gamepad) -> bool {
+        if let Some(action) = self.waiting {
+            let pressed = get_last_key_pressed()
+                .and_then(Binding::key)
+                .or_else(|| gamepad.last_pressed().map(Binding::Pad));
+            if is_key_pressed(CANCEL) {
+                self.waiting = None;
+            } else if is_key_pressed(REMOVE) {
+                bindings.unbind(action, self.slot);
+                self.waiting = None;
+            } else if let Some(binding) = pressed {
+                bindings.rebind(action, self.slot, binding);
+                self.waiting = None;
+            }
+            return false;
+        }
+
+        let controls = Controls::new(bindings, gamepad);
+        let event = self.menu.update(controls);
+        if let Some(&action) = Action::ALL.get(self.menu.selected) {
+            if controls.is_pressed(Action::Left) {
+                self.slot = self.slot.saturating_sub(1);
+            }
+            if controls.is_pressed(Action::Right) {
+                self.slot += 1;
+            }
+            self.slot = self.slot.min(bindings.get(action).len());
+        }
+        match event {
+            MenuEvent::Select(DEFAULTS) => *bindings = Bindings::default(),
+            MenuEvent::Select(BACK) | MenuEvent::Back => return true,
+            MenuEvent::Select(i) => self.waiting = Some(Action::ALL[i]),
+            MenuEvent::None => ()
+        }
+        false
+    }
+    pub fn draw(&self, bindings: &Bindings, strings: &Strings, params: &TextParams) {
+        dim_screen();
+
+        // while waiting, the last entries explain how to cancel or remove instead
+        let last = if self.waiting.is_some() {
+            [TextId::RebindCancel, TextId::RebindRemove]
+        } else {
+            [TextId::Defaults, TextId::Back]
+        };
+        let lines: Vec<String> = Action::ALL
+            .into_iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let name = strings.get(TextId::action(action));
+                if i != self.menu.selected {
+                    let labels: Vec<_> = [
+                        bindings.primary_key(action),
+                        bindings.primary_button(action)
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .map(Binding::label)
+                    .collect();
+                    return format!("{name} {}", labels.join("/"));
+                }
+                if self.waiting.is_some() {
+                    return format!("{name} ...");
+                }
+                let slot = bindings.get(action).get(self.slot);
+                format!("{name} <{}>", slot.map_or("+".to_string(), |b| b.label()))
+            })
+            .chain(last.map(|id| strings.get(id).to_string()))
+            .collect();
+        let items: Vec<&str> = lines.iter().map(String::as_str).collect();
+
+        draw_title(strings.get(TextId::Controls), params);
+        self.menu.draw(&items, 22.00, params);
+    }
+}
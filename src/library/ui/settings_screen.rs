@@ -0,0 +1,125 @@
+use crate::*;
+
+/// How much a single press changes a volume.
+const VOLUME_STEP: f32 = 0.10;
+
+/// Entries of the screen, in order.
+const MASTER: usize = 0;
+const MUSIC: usize = 1;
+const SFX: usize = 2;
+const SCALE_ITEM: usize = 3;
+const FULLSCREEN: usize = 4;
+const FLASH: usize = 5;
+const COLORBLIND: usize = 6;
+const CONTROLS: usize = 7;
+const LANGUAGE: usize = 8;
+const BACK: usize = 9;
+
+/// Lists the preferences, changed with left and right (or dash, which steps forwards).
+pub struct SettingsScreen {
+    menu: Menu
+}
+
+impl Default for SettingsScreen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SettingsScreen {
+    pub fn new() -> Self {
+        Self {
+            menu: Menu::new(BACK + 1)
+        }
+    }
+    /// Updates the screen, returning `true` once the player leaves it.
+    pub fn update(&mut self, settings: &mut Settings, gamepad: &Gamepad) -> bool {
+        let controls = Controls::new(&settings.bindings, gamepad);
+        let step = match self.menu.update(controls) {
+            MenuEvent::Select(BACK) | MenuEvent::Back => return true,
+            MenuEvent::Select(_) => 1,
+            MenuEvent::None if controls.is_pressed(Action::Right) => 1,
+            MenuEvent::None if controls.is_pressed(Action::Left) => -1,
+            MenuEvent::None => return false
+        };
+
+        let volumes = &mut settings.volumes;
+        match self.menu.selected {
+            MASTER => step_volume(&mut volumes.master, step),
+            MUSIC => step_volume(&mut volumes.music, step),
+            SFX => step_volume(&mut volumes.sfx, step),
+            SCALE_ITEM => {
+                settings.window_scale = settings
+                    .window_scale
+                    .saturating_add_signed(step)
+                    .clamp(1, MAX_SCALE);
+            }
+            FULLSCREEN => settings.fullscreen = !settings.fullscreen,
+            FLASH => settings.reduced_flash = !settings.reduced_flash,
+            COLORBLIND => settings.colorblind = !settings.colorblind,
+            CONTROLS => settings.controls = settings.controls.next(),
+            LANGUAGE => step_language(&mut settings.language, step),
+            _ => ()
+        }
+        false
+    }
+    pub fn draw(&self, settings: &Settings, strings: &Strings, params: &TextParams) {
+        dim_screen();
+
+        let volumes = settings.volumes;
+        let flash = if settings.reduced_flash {
+            TextId::FlashReduced
+        } else {
+            TextId::FlashFull
+        };
+        let scheme = match settings.controls {
+            ControlScheme::Keys => TextId::SchemeKeys,
+            ControlScheme::Mouse => TextId::SchemeMouse
+        };
+        let lines = [
+            strings.fmt(TextId::Master, &[&percent(volumes.master)]),
+            strings.fmt(TextId::Music, &[&percent(volumes.music)]),
+            strings.fmt(TextId::Sfx, &[&percent(volumes.sfx)]),
+            strings.fmt(TextId::Scale, &[&settings.window_scale.to_string()]),
+            strings.fmt(TextId::Fullscreen, &[on_off(strings, settings.fullscreen)]),
+            strings.fmt(TextId::Flash, &[strings.get(flash)]),
+            strings.fmt(TextId::Colorblind, &[on_off(strings, settings.colorblind)]),
+            strings.fmt(TextId::Move, &[strings.get(scheme)]),
+            strings.fmt(TextId::Language, &[&settings.language]),
+            strings.get(TextId::Back).to_string()
+        ];
+        let items: Vec<&str> = lines.iter().map(String::as_str).collect();
+
+        draw_title(strings.get(TextId::Settings), params);
+        self.menu.draw(&items, 20.00, params);
+    }
+}
+
+fn step_volume(volume: &mut f32, step: i32) {
+    // round to whole steps so repeated presses don't drift
+    let steps = (*volume / VOLUME_STEP).round() + step as f32;
+    *volume = (steps * VOLUME_STEP).clamp(0.00, 1.00);
+}
+
+fn percent(volume: f32) -> String {
+    format!("{}%", (volume * 100.00).round())
+}
+
+fn on_off(strings: &Strings, value: bool) -> &str {
+    strings.get(TextId::on_off(value))
+}
+
+fn step_language(language: &mut String, step: i32) {
+    let languages = Strings::languages();
+    let len = languages.len();
+    if len == 0 {
+        return;
+    }
+    let current = languages.iter().position(|l| l == language).unwrap_or(0);
+    let next = if step < 0 {
+        (current + len - 1) % len
+    } else {
+        (current + 1) % len
+    };
+    language.clone_from(&languages[next]);
+}
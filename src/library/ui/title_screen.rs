@@ -0,0 +1,101 @@
+use std::fmt::Write;
+
+use crate::*;
+
+/// High scores listed below the presets.
+const SHOWN_SCORES: usize = 2;
+/// Entries of the screen after the presets, in order.
+const ADAPTIVE: usize = Preset::COUNT;
+const PRACTICE: usize = Preset::COUNT + 1;
+
+/// What happened on the [`TitleScreen`] this frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TitleEvent {
+    None,
+    /// A preset was picked to start a run on.
+    Start(Preset),
+    /// The player wants to set up a practice run.
+    Practice
+}
+
+/// Shown when the game starts. Lists the difficulty presets, with the best runs of the
+/// selected one below, whether the difficulty adapts to the players, and practice mode.
+pub struct TitleScreen {
+    menu:     Menu,
+    /// High scores of every preset, without and with adaptive difficulty.
+    fixed:    Vec<HighScores>,
+    adaptive: Vec<HighScores>
+}
+
+impl TitleScreen {
+    /// Creates the screen with the given preset selected, loading every high score table.
+    pub fn new(preset: Preset) -> Self {
+        let mut menu = Menu::new(PRACTICE + 1);
+        menu.selected = preset.index();
+        let tables = |adaptive| {
+            Preset::ALL
+                .into_iter()
+                .map(|preset| HighScores::load(preset, adaptive))
+                .collect()
+        };
+        Self {
+            menu,
+            fixed: tables(false),
+            adaptive: tables(true)
+        }
+    }
+    /// Updates the screen, toggling the adaptive difficulty in the settings.
+    pub fn update(&mut self, settings: &mut Settings, gamepad: &Gamepad) -> TitleEvent {
+        let controls = Controls::new(&settings.bindings, gamepad);
+        let toggle = controls.is_pressed(Action::Left) || controls.is_pressed(Action::Right);
+        match self.menu.update(controls) {
+            MenuEvent::Select(ADAPTIVE) => settings.adaptive = !settings.adaptive,
+            MenuEvent::Select(PRACTICE) => return TitleEvent::Practice,
+            MenuEvent::Select(i) => return TitleEvent::Start(Preset::ALL[i]),
+            MenuEvent::None if toggle && self.menu.selected == ADAPTIVE => {
+                settings.adaptive = !settings.adaptive;
+            }
+            MenuEvent::Back | MenuEvent::None => ()
+        }
+        TitleEvent::None
+    }
+    pub fn draw(&self, settings: &Settings, strings: &Strings, params: &TextParams) {
+        dim_screen();
+        draw_title(strings.get(TextId::Title), params);
+
+        let adaptive = strings.fmt(
+            TextId::Adaptive,
+            &[strings.get(TextId::on_off(settings.adaptive))]
+        );
+        let mut items: Vec<&str> = Preset::ALL
+            .iter()
+            .map(|preset| strings.get(preset.text()))
+            .collect();
+        items.push(&adaptive);
+        items.push(strings.get(TextId::Practice));
+        self.menu.draw(&items, 22.00, params);
+
+        // the other entries show the preset the next run would start on
+        let shown = Preset::ALL
+            .get(self.menu.selected)
+            .copied()
+            .unwrap_or(settings.preset);
+        let tables = if settings.adaptive {
+            &self.adaptive
+        } else {
+            &self.fixed
+        };
+        let mut lines = vec![strings.get(TextId::HighScores).to_string()];
+        let entries = &tables[shown.index()].entries;
+        for (rank, entry) in entries.iter().take(SHOWN_SCORES).enumerate() {
+            let mut line = format!("{}. ", rank + 1);
+            fill_leading_zeroes(&mut line, entry.score);
+            write!(line, " {}", format_time(entry.time)).expect("string write cannot fail");
+            lines.push(line);
+        }
+        if entries.is_empty() {
+            lines.push("-".to_string());
+        }
+        TextBox::centered(90.00).draw(&lines.join("\n"), params);
+    }
+}
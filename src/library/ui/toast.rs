@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+
+use crate::*;
+
+/// Ticks a toast stays on screen.
+const TOAST_TIME: f64 = 180.00;
+/// Ticks a toast takes to slide in and out.
+const TOAST_SLIDE: f64 = 12.00;
+const TOAST_HEIGHT: f32 = 24.00;
+
+const TOAST_BACK: Color = color_u8!(0, 0, 0, 200);
+const TOAST_HEADING: Color = color_u8!(255, 215, 55, 255);
+
+/// A short notice, such as an unlocked achievement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Toast {
+    heading: String,
+    text:    String
+}
+
+/// Notices shown at the top of the screen during play, one at a time.
+#[derive(Debug, Default)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+    /// Ticks the front toast has been shown for.
+    age:   f64
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Queues a toast, shown once those before it are gone.
+    pub fn push(&mut self, heading: &str, text: &str) {
+        self.queue.push_back(Toast {
+            heading: heading.to_string(),
+            text:    text.to_string()
+        });
+    }
+    pub fn update(&mut self, dt: f64) {
+        if self.queue.is_empty() {
+            return;
+        }
+        self.age += dt;
+        if self.age >= TOAST_TIME {
+            self.queue.pop_front();
+            self.age = 0.00;
+        }
+    }
+    pub fn draw(&self, params: &TextParams) {
+        let Some(toast) = self.queue.front() else {
+            return;
+        };
+        // slide down from above the screen, then back up
+        let shown = (self.age.min(TOAST_TIME - self.age) / TOAST_SLIDE).min(1.00) as f32;
+        let top = 12.00 - (12.00 + TOAST_HEIGHT) * (1.00 - shown);
+        draw_rectangle(
+            8.00,
+            top.round(),
+            SCREEN_X as f32 - 16.00,
+            TOAST_HEIGHT,
+            TOAST_BACK
+        );
+        let heading = TextParams {
+            color: TOAST_HEADING,
+            ..params.clone()
+        };
+        draw_text_aligned(
+            &toast.heading,
+            CENTER_X as f32,
+            top + 10.00,
+            Align::Center,
+            &heading
+        );
+        draw_text_aligned(
+            &toast.text,
+            CENTER_X as f32,
+            top + 21.00,
+            Align::Center,
+            params
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toasts_show_one_at_a_time() {
+        let mut toasts = Toasts::new();
+        toasts.push("a", "1");
+        toasts.push("b", "2");
+        toasts.update(TOAST_TIME - 1.00);
+        assert_eq!(toasts.queue.front().map(|t| t.heading.as_str()), Some("a"));
+        toasts.update(1.00);
+        assert_eq!(toasts.queue.front().map(|t| t.heading.as_str()), Some("b"));
+        toasts.update(TOAST_TIME);
+        assert!(toasts.queue.is_empty());
+    }
+}
@@ -6,4 +6,6 @@ pub mod prob;
 pub use prob::*;
 pub mod counter;
 pub use counter::*;
+pub mod date;
 pub mod fps;
+pub use date::*;
@@ -4,14 +4,48 @@ pub fn rand(x: f64) -> f64 {
     rand::gen_range(0.00, x)
 }
 
-pub fn rrange(x: i32) -> i32 {
-    rand::gen_range(0, x + 1)
+pub fn rand_between(lo: f64, hi: f64) -> f64 {
+    lo + rand(hi - lo)
 }
 
-pub fn chance(x: f64) -> bool {
-    rand(1.00) < x
+/// A random number generator of its own, for what has to play out the same on every run with
+/// the same seed: the hazards and the cheese. Effects such as particles draw from the global
+/// generator, so they don't change what spawns.
+pub struct Rng(rand::RandGenerator);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        let generator = rand::RandGenerator::new();
+        generator.srand(seed);
+        Rng(generator)
+    }
+    pub fn rand(&self, x: f64) -> f64 {
+        self.0.gen_range(0.00, x)
+    }
+    pub fn rrange(&self, x: i32) -> i32 {
+        self.0.gen_range(0, x + 1)
+    }
+    /// Picks a random item, or `None` if there are none.
+    pub fn pick<'a, T>(&self, items: &'a [T]) -> Option<&'a T> {
+        items.get(self.0.gen_range(0, items.len()))
+    }
+    pub fn chance(&self, x: f64) -> bool {
+        self.rand(1.00) < x
+    }
+    pub fn rand_dir(&self) -> Vector2 {
+        num_to_side(self.rrange(4))
+    }
 }
 
-pub fn get_rand_dir() -> Vector2 {
-    num_to_side(rrange(4))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_repeats_on_seed() {
+        let (a, b) = (Rng::new(5), Rng::new(5));
+        rand::gen_range(0, 100);
+        let draws = |rng: &Rng| [rng.rrange(100), rng.rrange(100), rng.rrange(100)];
+        assert_eq!(draws(&a), draws(&b));
+    }
 }
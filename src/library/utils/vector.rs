@@ -2,7 +2,7 @@ use std::ops::{Add, AddAssign, Mul, MulAssign, Sub};
 
 /// A 2D vector with `f64` components.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Vector2(pub f64, pub f64);
 
 impl From<f64> for Vector2 {
@@ -15,6 +15,11 @@ impl From<(f64, f64)> for Vector2 {
         Self(value.0, value.1)
     }
 }
+impl From<(f32, f32)> for Vector2 {
+    fn from(value: (f32, f32)) -> Self {
+        Self(f64::from(value.0), f64::from(value.1))
+    }
+}
 
 impl Vector2 {
     /// The null vector.
@@ -173,6 +178,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::float_cmp)]
     fn test_vector2() {
         let v = Vector2(1.00, 2.00);
         assert_eq!(v.square_len(), 5.00);
@@ -0,0 +1,96 @@
+//! Fitting the game canvas into the window.
+//!
+//! The canvas is scaled by the largest whole factor that fits so every game pixel stays the
+//! same size, and the rest of the window is letterboxed.
+
+use macroquad::prelude::*;
+use macroquad_canvas::Canvas2D;
+
+use crate::library::{SCREEN_X, SCREEN_Y, Vector2};
+
+/// Colour of the bars around the canvas.
+const LETTERBOX: Color = BLACK;
+
+/// Where the canvas sits in the window, in window pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub scale: f32,
+    pub left:  f32,
+    pub top:   f32
+}
+
+impl Viewport {
+    /// Fits a canvas of the game's size into a window of the given size.
+    ///
+    /// Windows smaller than the canvas shrink it to fit instead, since there is no whole
+    /// factor left to use.
+    pub fn fit(window_width: f32, window_height: f32) -> Viewport {
+        let (width, height) = (SCREEN_X as f32, SCREEN_Y as f32);
+        let fit = f32::min(window_width / width, window_height / height);
+        let scale = if fit >= 1.00 { fit.floor() } else { fit };
+        Viewport {
+            scale,
+            left: ((window_width - width * scale) * 0.50).floor(),
+            top: ((window_height - height * scale) * 0.50).floor()
+        }
+    }
+    /// Fits the canvas into the window as it is right now.
+    pub fn current() -> Viewport {
+        Viewport::fit(screen_width(), screen_height())
+    }
+    /// Maps a point in the window onto the canvas, clamped to its edges.
+    pub fn to_canvas(&self, (x, y): (f32, f32)) -> Vector2 {
+        let x = ((x - self.left) / self.scale).clamp(0.00, SCREEN_X as f32);
+        let y = ((y - self.top) / self.scale).clamp(0.00, SCREEN_Y as f32);
+        Vector2(f64::from(x), f64::from(y))
+    }
+    /// Returns the mouse position on the canvas.
+    pub fn mouse_position(&self) -> Vector2 {
+        self.to_canvas(macroquad::input::mouse_position())
+    }
+    /// Draws the canvas into the window, letterboxing the rest.
+    ///
+    /// Expects the default camera to be set.
+    pub fn draw(&self, canvas: &Canvas2D) {
+        clear_background(LETTERBOX);
+        draw_texture_ex(
+            canvas.get_texture(),
+            self.left,
+            self.top,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(
+                    SCREEN_X as f32 * self.scale,
+                    SCREEN_Y as f32 * self.scale
+                )),
+                ..Default::default()
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_viewport_fit() {
+        // 1000x700 fits 5x (800x600) with bars on every side
+        let viewport = Viewport::fit(1000.00, 700.00);
+        assert_eq!(viewport.scale, 5.00);
+        assert_eq!((viewport.left, viewport.top), (100.00, 50.00));
+
+        let small = Viewport::fit(80.00, 120.00);
+        assert!(small.scale < 1.00);
+    }
+
+    #[test]
+    fn test_viewport_to_canvas() {
+        let viewport = Viewport::fit(1000.00, 700.00);
+        let Vector2(x, y) = viewport.to_canvas((105.00, 60.00));
+        assert!((x - 1.00).abs() < 1e-6 && (y - 2.00).abs() < 1e-6);
+        let Vector2(x, y) = viewport.to_canvas((0.00, 999.00));
+        assert!(x.abs() < 1e-6 && (y - SCREEN_Y).abs() < 1e-6);
+    }
+}
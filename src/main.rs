@@ -1,7 +1,10 @@
 //#![windows_subsystem = "windows"]
 #![allow(clippy::missing_panics_doc)]
 
-use std::{f64::consts::PI, ops::Not};
+use std::{
+    f64::consts::{PI, TAU},
+    ops::Not
+};
 
 use library::*;
 use macroquad::prelude::*;
@@ -30,34 +33,16 @@ async fn main() {
     let mut canvas = Canvas2D::new(SCREEN_X as f32, SCREEN_Y as f32);
     canvas.get_texture_mut().set_filter(FilterMode::Nearest);
 
-    // asset loading
-    let mut asset_loader = AssetLoader::new();
-    asset_loader
-        .load_sprites(vec![
-            "burger",
-            "burger_invuln",
-            "bullet",
-            "flak",
-            "slug",
-            "flak_child",
-        ])
-        .await
-        .load_sprites(vec![
-            ("cheese", Color::from_rgba(255, 221, 86, 255)),
-            ("heart", Color::from_rgba(221, 16, 85, 255)),
-        ])
-        .await
-        .load_sounds(vec!["explosion", "heal", "laser", "damage", "dash"])
-        .await
-        .load_sounds(vec![(0.15, true, "music1")])
-        .await;
+    let mut asset_loader = load_assets().await;
 
-    let mut joystix = load_ttf_font("joystix.otf").await.unwrap();
-    joystix.set_filter(FilterMode::Nearest);
+    let joystix = load_font();
 
     // state init
-    let mut state = State::reset();
+    let mut settings = Settings::load();
+    let mut strings = Strings::load(&settings.language);
+    let (mut state, mut race) = new_run(&settings, false);
     let mut ended = false;
+    let mut achievements = Achievements::load();
 
     // tests
     let text_params = TextParams {
@@ -66,55 +51,92 @@ async fn main() {
     };
 
     // music
-    asset_loader.play_sound("music1");
+    asset_loader.set_volumes(settings.volumes);
+    asset_loader.play_sound(SoundId::Music1);
 
-    let mut score_text = String::with_capacity(5);
-    fill_leading_zeroes(&mut score_text, state.score);
+    let mut gamepad = start_gamepad();
+    let mut screen = Screen::Title(TitleScreen::new(settings.preset));
+
+    let mut hud = Hud::new();
+    let mut toasts = Toasts::new();
     // main game loop
     loop {
         // get inputs for this frame
-        let input = Input::get();
-
-        let last_score = state.score;
-        if ended.not() && state.frozen_time == 0 {
-            state.run_systems(dt, &input, &asset_loader);
+        gamepad.poll();
+        let controls = Controls::new(&settings.bindings, &gamepad);
+        let inputs = player_inputs(controls, &settings, &state);
+
+        match &mut screen {
+            Screen::Playing => {
+                if controls.is_pressed(Action::Pause) {
+                    screen = Screen::Paused(Menu::new(PAUSE_ITEMS));
+                } else {
+                    if ended.not() && state.frozen_time == 0 {
+                        state.run_systems(dt, &inputs, &asset_loader, &settings);
+                        let burgers = state.burgers.iter().filter(|b| b.is_alive()).map(|b| b.pos);
+                        race.record(dt, burgers.collect(), state.score);
+                        unlock_achievements(&mut achievements, &mut state, &mut toasts, &strings);
+                    }
+                    toasts.update(dt);
+                    state.frozen_time -= std::cmp::min(freeze_decay, state.frozen_time);
+                }
+                if ended && controls.is_pressed(Action::Restart) {
+                    (state, race) = new_run(&settings, state.stats.practice);
+                }
+            }
+            Screen::Title(_) | Screen::Practice(_) => {
+                if let Some(run) = update_start_screen(&mut screen, &mut settings, &gamepad) {
+                    (state, race) = run;
+                }
+            }
+            Screen::Paused(menu) => match menu.update(controls) {
+                MenuEvent::Select(item) => {
+                    screen = select_pause_item(item, &mut settings, &mut state, &mut race);
+                }
+                MenuEvent::Back => screen = Screen::Playing,
+                MenuEvent::None => ()
+            },
+            Screen::Rebinding(rebind) => {
+                if rebind.update(&mut settings.bindings, &gamepad) {
+                    save_settings(&settings);
+                    screen = Screen::Paused(Menu::new(PAUSE_ITEMS));
+                }
+            }
+            Screen::Achievements(list) => {
+                if list.update(controls) {
+                    screen = Screen::Paused(Menu::new(PAUSE_ITEMS));
+                }
+            }
+            Screen::Settings(options) => {
+                let before = settings.clone();
+                if options.update(&mut settings, &gamepad) {
+                    save_settings(&settings);
+                    screen = Screen::Paused(Menu::new(PAUSE_ITEMS));
+                }
+                apply_settings(&settings, &before, &mut asset_loader, &mut strings);
+            }
         }
-        state.frozen_time -= std::cmp::min(freeze_decay, state.frozen_time);
 
         // draw calls
         set_camera(&canvas.camera);
-        state.draw(&asset_loader);
+        state.draw(&asset_loader, &settings);
 
-        if last_score != state.score {
-            score_text.clear();
-            fill_leading_zeroes(&mut score_text, state.score);
-        }
-        draw_text_ex(&score_text, 1.00, 9.00, text_params.clone());
+        draw_ghost(&race, &state, &asset_loader);
+        draw_hud(&mut hud, &state, &race, dt, &text_params);
+        toasts.draw(&text_params);
 
         if ended {
-            let game_over = "you did not become cheeseburger";
-            draw_text_ex(
-                &game_over[..12],
-                35.00,
-                CENTER_Y as f32 - 20.00,
-                text_params.clone()
-            );
-            draw_text_ex(&game_over[12..], 1.00, CENTER_Y as f32, text_params.clone());
-            draw_text_ex(
-                "restart: [r]",
-                30.00,
-                CENTER_Y as f32 + 20.00,
-                text_params.clone()
-            );
-            if is_key_pressed(KeyCode::R) {
-                state = State::reset();
-            }
+            draw_game_over(&state.stats, &settings.bindings, &strings, &text_params);
         }
+        screen.draw(&settings, &achievements, &strings, &text_params);
 
         set_default_camera();
-        canvas.draw();
+        Viewport::current().draw(&canvas);
 
         // game should only end after freeze frames are rendered, so this goes after draw calls
+        if ended.not() && state.game_is_over() {
+            finish_run(&mut race, &state.stats);
+        }
         ended = state.game_is_over();
 
         // wait for the frame timer
@@ -122,6 +144,316 @@ async fn main() {
     }
 }
 
+/// Loads every sprite and sound used by the game.
+async fn load_assets() -> AssetLoader {
+    let mut asset_loader = AssetLoader::new();
+    asset_loader
+        .load_sprites(vec![
+            SpriteId::Burger,
+            SpriteId::BurgerInvuln,
+            SpriteId::Bullet,
+            SpriteId::Flak,
+            SpriteId::Slug,
+            SpriteId::FlakChild,
+        ])
+        .load_sprites(vec![
+            (SpriteId::Cheese, Color::from_rgba(255, 221, 86, 255)),
+            (SpriteId::Heart, Color::from_rgba(221, 16, 85, 255)),
+        ])
+        .load_sounds(vec![
+            SoundId::Explosion,
+            SoundId::Heal,
+            SoundId::Laser,
+            SoundId::Damage,
+            SoundId::Dash,
+        ])
+        .await
+        .load_sounds(vec![(0.15, true, SoundId::Music1)])
+        .await;
+    asset_loader
+}
+
+/// Loads the pixel font all text is drawn in.
+fn load_font() -> Font {
+    let font_bytes = pack::read("joystix.otf").expect("font should be in the asset pack");
+    let mut joystix = load_ttf_font_from_bytes(&font_bytes).unwrap();
+    joystix.set_filter(FilterMode::Nearest);
+    joystix
+}
+
+const PAUSE_ITEMS: usize = 7;
+
+/// Tints telling the players apart in co-op.
+const PLAYER_TINTS: [Color; 2] = [WHITE, color_u8!(175, 215, 255, 255)];
+
+/// Gathers the input of every player. In co-op, the first player uses the keyboard (or mouse)
+/// and the second one the gamepad.
+fn player_inputs(controls: Controls, settings: &Settings, state: &State) -> Vec<Input> {
+    let first = if settings.coop {
+        controls.only(Device::Keyboard)
+    } else {
+        controls
+    };
+    let first = match settings.controls {
+        ControlScheme::Keys => Input::get(first),
+        ControlScheme::Mouse => Input::from_mouse(
+            first,
+            Viewport::current().mouse_position(),
+            state.burgers[0].pos
+        )
+    };
+
+    if settings.coop {
+        vec![first, Input::get(controls.only(Device::Gamepad))]
+    } else {
+        vec![first]
+    }
+}
+
+/// Starts a new run on the configured seed and preset, racing the best run on the same seed,
+/// preset, number of players and kind of difficulty.
+/// Practice runs are set up as configured for practice instead.
+fn new_run(settings: &Settings, practice: bool) -> (State, Race) {
+    let seed = settings.run_seed();
+    let adaptive = settings.adaptive && !practice;
+    let race = Race::start(RaceKey {
+        seed,
+        preset: settings.preset,
+        coop: settings.coop,
+        adaptive
+    });
+    let mut state = State::reset(settings.players(), settings.preset, seed);
+    if practice {
+        state.practice = Some(settings.practice);
+        state.difficulty = settings.practice.difficulty;
+    } else if adaptive {
+        state.director = Some(Director::new(state.difficulty));
+    }
+    state.stats.practice = practice;
+    state.stats.adaptive = adaptive;
+    (state, race)
+}
+
+/// Updates the title or practice screen, returning the new run once one starts.
+fn update_start_screen(
+    screen: &mut Screen,
+    settings: &mut Settings,
+    gamepad: &Gamepad
+) -> Option<(State, Race)> {
+    let practice = match screen {
+        Screen::Title(title) => match title.update(settings, gamepad) {
+            TitleEvent::Start(preset) => {
+                settings.preset = preset;
+                false
+            }
+            TitleEvent::Practice => {
+                *screen = Screen::Practice(PracticeScreen::new());
+                return None;
+            }
+            TitleEvent::None => return None
+        },
+        Screen::Practice(setup) => match setup.update(settings, gamepad) {
+            PracticeEvent::Start => true,
+            PracticeEvent::Back => {
+                save_settings(settings);
+                *screen = Screen::Title(TitleScreen::new(settings.preset));
+                return None;
+            }
+            PracticeEvent::None => return None
+        },
+        _ => return None
+    };
+    save_settings(settings);
+    *screen = Screen::Playing;
+    Some(new_run(settings, practice))
+}
+
+/// Draws the ghosts of the burgers of the best run, unless practicing.
+fn draw_ghost(race: &Race, state: &State, asset_loader: &AssetLoader) {
+    let Some(ghost) = race.ghost().filter(|_| state.practice.is_none()) else {
+        return;
+    };
+    for &pos in &ghost.burgers {
+        copy_tinted(
+            asset_loader.texture(SpriteId::Burger),
+            pos,
+            Color::new(1.00, 1.00, 1.00, 0.35)
+        );
+    }
+}
+
+/// Updates and draws the HUD for this frame.
+fn draw_hud(hud: &mut Hud, state: &State, race: &Race, dt: f64, params: &TextParams) {
+    let frame = HudFrame {
+        score:      state.score,
+        time:       race.time,
+        // practice runs don't race
+        delta:      race.delta(state.score).filter(|_| state.practice.is_none()),
+        multiplier: state.combo.multiplier(),
+        burgers:    &state.burgers
+    };
+    hud.update(&frame, dt);
+    hud.draw(&frame, params);
+}
+
+/// Acts on the chosen pause menu item, returning the screen to show next.
+fn select_pause_item(
+    item: usize,
+    settings: &mut Settings,
+    state: &mut State,
+    race: &mut Race
+) -> Screen {
+    let practice = state.stats.practice;
+    match item {
+        0 => return Screen::Playing,
+        1 => return Screen::Rebinding(RebindScreen::new()),
+        2 => return Screen::Settings(SettingsScreen::new()),
+        3 => return Screen::Achievements(AchievementsScreen::new()),
+        4 => {
+            settings.coop = !settings.coop;
+            save_settings(settings);
+        }
+        5 => {
+            (*state, *race) = new_run(settings, false);
+            return Screen::Title(TitleScreen::new(settings.preset));
+        }
+        _ => ()
+    }
+    (*state, *race) = new_run(settings, practice);
+    Screen::Playing
+}
+
+/// Saves the replay of a run that just ended, if it is the new best, and enters it into the
+/// high scores of its preset and kind of difficulty. Also exports its statistics. Practice runs
+/// are only exported.
+fn finish_run(race: &mut Race, stats: &RunStats) {
+    if !stats.practice {
+        let mut scores = HighScores::load(stats.preset, stats.adaptive);
+        if scores.insert(stats.score, stats.time).is_some()
+            && let Err(err) = scores.save()
+        {
+            eprintln!("Failed to save high scores: {err}");
+        }
+        if let Err(err) = race.finish() {
+            eprintln!("Failed to save replay: {err}");
+        }
+    }
+    if let Err(err) = stats.export() {
+        eprintln!("Failed to export run statistics: {err}");
+    }
+}
+
+/// Unlocks whatever the run has earned so far, announcing each new achievement. Practice
+/// runs earn nothing.
+fn unlock_achievements(
+    achievements: &mut Achievements,
+    state: &mut State,
+    toasts: &mut Toasts,
+    strings: &Strings
+) {
+    let events = std::mem::take(&mut state.events);
+    if state.stats.practice {
+        return;
+    }
+    let unlocked = achievements.check(&state.stats, &events);
+    if unlocked.is_empty() {
+        return;
+    }
+    for id in unlocked {
+        toasts.push(
+            strings.get(TextId::AchievementToast),
+            strings.get(id.text().0)
+        );
+    }
+    if let Err(err) = achievements.save() {
+        eprintln!("Failed to save achievements: {err}");
+    }
+}
+
+/// Applies whatever changed between two versions of the settings.
+fn apply_settings(
+    settings: &Settings,
+    before: &Settings,
+    asset_loader: &mut AssetLoader,
+    strings: &mut Strings
+) {
+    if settings.language != before.language {
+        *strings = Strings::load(&settings.language);
+    }
+    if settings.volumes != before.volumes {
+        asset_loader.set_volumes(settings.volumes);
+    }
+    if settings.fullscreen != before.fullscreen {
+        set_fullscreen(settings.fullscreen);
+    }
+    let resized = settings.window_scale != before.window_scale;
+    if !settings.fullscreen && (resized || settings.fullscreen != before.fullscreen) {
+        let scale = settings.window_scale as f32;
+        request_new_screen_size(SCREEN_X as f32 * scale, SCREEN_Y as f32 * scale);
+    }
+}
+
+fn start_gamepad() -> Gamepad {
+    Gamepad::new().unwrap_or_else(|err| {
+        eprintln!("Failed to start gamepad support: {err}");
+        Gamepad::disconnected()
+    })
+}
+
+fn save_settings(settings: &Settings) {
+    if let Err(err) = settings.save() {
+        eprintln!("Failed to save settings: {err}");
+    }
+}
+
+/// What the main loop is currently showing on top of the game.
+enum Screen {
+    Title(TitleScreen),
+    Practice(PracticeScreen),
+    Playing,
+    Paused(Menu),
+    Rebinding(RebindScreen),
+    Settings(SettingsScreen),
+    Achievements(AchievementsScreen)
+}
+
+impl Screen {
+    fn draw(
+        &self,
+        settings: &Settings,
+        achievements: &Achievements,
+        strings: &Strings,
+        params: &TextParams
+    ) {
+        match self {
+            Screen::Title(title) => title.draw(settings, strings, params),
+            Screen::Practice(setup) => setup.draw(settings, strings, params),
+            Screen::Playing => (),
+            Screen::Paused(menu) => {
+                dim_screen();
+                draw_title(strings.get(TextId::Paused), params);
+                let players = strings.fmt(TextId::Players, &[&settings.players().to_string()]);
+                menu.draw(
+                    &[
+                        strings.get(TextId::Resume),
+                        strings.get(TextId::Controls),
+                        strings.get(TextId::Settings),
+                        strings.get(TextId::Achievements),
+                        &players,
+                        strings.get(TextId::MainMenu),
+                        strings.get(TextId::Restart)
+                    ],
+                    22.00,
+                    params
+                );
+            }
+            Screen::Rebinding(rebind) => rebind.draw(&settings.bindings, strings, params),
+            Screen::Settings(options) => options.draw(settings, strings, params),
+            Screen::Achievements(list) => list.draw(achievements, strings, params)
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Counters {
     bullet:      f64,
@@ -135,149 +467,226 @@ pub struct Counters {
 pub struct State {
     frozen_time: u32,
 
+    preset:     Preset,
     difficulty: f64,
+    /// Adapts the difficulty instead of the linear ramp, if enabled.
+    director:   Option<Director>,
+    /// How the run is set up, if it is a practice run.
+    practice:   Option<Practice>,
     score:      i32,
+    combo:      Combo,
+    stats:      RunStats,
+    /// What happened this frame, for achievements to react to.
+    events:     Vec<GameEvent>,
     counters:   Counters,
+    /// Spawns hazards and cheese, seeded with the run's seed.
+    rng:        Rng,
 
     entities:  Vec<Entity>,
     // instances
-    burger:    Player,
+    burgers:   Vec<Player>,
     cheese:    Cheese,
     particles: Vec<Particle>
 }
 
 impl State {
-    fn run_systems(&mut self, dt: f64, input: &Input, assets: &AssetLoader) {
+    fn run_systems(
+        &mut self,
+        dt: f64,
+        inputs: &[Input],
+        assets: &AssetLoader,
+        settings: &Settings
+    ) {
+        let hazards = settings.hazards();
         sys::spawn::run(self, dt);
 
-        sys::dash::run(self, dt, input, assets);
+        sys::dash::run(self, dt, inputs, assets, settings);
         sys::pos::run(self, dt);
         sys::age::run(self, dt);
-        sys::player_collide::run(self, assets);
-        sys::cheese::run(self, assets);
+        sys::player_collide::run(self, assets, hazards);
+        sys::graze::run(self, assets, hazards);
+        sys::combo::run(self, dt);
+        sys::cheese::run(self, assets, hazards);
         sys::friction::run(self, dt);
         sys::bound_burger::run(self);
         sys::bound_stats::run(self);
+        sys::trail::run(self);
 
-        sys::destroy_old::run(self, assets);
+        sys::destroy_old::run(self, assets, hazards);
         sys::destroy_dead::run(&mut self.entities);
 
         sys::difficulty_up::run(self, dt);
+        sys::stats::run(self, dt);
     }
 
-    fn draw(&self, asset_loader: &AssetLoader) {
-        let bg = if self.frozen_time == 0 {
+    fn draw(&self, asset_loader: &AssetLoader, settings: &Settings) {
+        let hazards = settings.hazards();
+        let bg = if self.frozen_time == 0 || settings.reduced_flash {
             BG
         } else {
             BG_ON_DAMAGE
         };
         clear_background(bg);
-        // burger
-        let b_sprite = if self.burger.invuln > 0.00 {
-            asset_loader.texture("burger_invuln")
-        } else {
-            asset_loader.texture("burger")
-        };
-        copy_texture(b_sprite, self.burger.pos);
+        self.draw_trails(asset_loader, hazards);
+        // burgers
+        for (i, burger) in self.burgers.iter().enumerate() {
+            if !burger.is_alive() {
+                continue;
+            }
+            let b_sprite = if burger.invuln > 0.00 {
+                asset_loader.texture(SpriteId::BurgerInvuln)
+            } else {
+                asset_loader.texture(SpriteId::Burger)
+            };
+            copy_tinted(b_sprite, burger.pos, PLAYER_TINTS[i % PLAYER_TINTS.len()]);
+        }
         // cheese
-        copy_texture(asset_loader.texture("cheese"), self.cheese.pos);
+        copy_texture(asset_loader.texture(SpriteId::Cheese), self.cheese.pos);
         let cpos = self.cheese.pos;
         let to_next = self.cheese.next_pos - cpos;
         draw::rec(
             cpos + (to_next.normal() * 10.00),
             2,
             2,
-            *asset_loader.color("cheese")
+            *asset_loader.color(SpriteId::Cheese)
         );
 
         // particles
         for particle in &self.particles {
-            let (w, h) = (2, 2);
-            draw::rec(particle.pos, w, h, particle.color);
+            draw::rec(
+                particle.pos,
+                particle.size,
+                particle.size,
+                particle.current_color()
+            );
         }
 
         for e in &self.entities {
             match e.class {
                 Class::Slug => {
                     copy_with_rotation(
-                        asset_loader.texture("slug"),
+                        asset_loader.texture(SpriteId::Slug),
                         e.pos,
                         e.vel.angle() + PI * 0.50
                     );
                 }
                 Class::Warning { delay, dir: _ } => {
                     if e.age >= delay {
-                        let dur = 6.00;
-                        let clr = if e.age % dur < dur * 0.50 {
-                            Color::from_rgba(255, 55, 55, 255)
-                        } else {
-                            Color::from_rgba(255, 255, 55, 255)
-                        };
+                        let clr = warning_color(e.age, hazards, settings.reduced_flash);
                         draw::rec(e.pos, 10, 10, clr);
                     }
                 }
                 Class::Laser => {
-                    let (w, h) = if e.vel.x().abs() > e.vel.y().abs() {
-                        (36, 6)
-                    } else {
-                        (6, 36)
-                    };
-                    draw::rec(e.pos, w, h, Color::from_rgba(255, 55, 55, 255));
+                    let (w, h) = laser_size(e.vel);
+                    draw::rec(e.pos, w, h, hazards.laser);
                 }
-                Class::Bullet => copy_texture(asset_loader.texture("bullet"), e.pos),
-                Class::HealthPack => copy_texture(asset_loader.texture("heart"), e.pos),
-                Class::Flak => copy_texture(asset_loader.texture("flak"), e.pos),
-                Class::FlakChild => copy_texture(asset_loader.texture("flak_child"), e.pos),
+                Class::Bullet => copy_texture(asset_loader.texture(SpriteId::Bullet), e.pos),
+                Class::HealthPack => copy_texture(asset_loader.texture(SpriteId::Heart), e.pos),
+                Class::Flak => copy_texture(asset_loader.texture(SpriteId::Flak), e.pos),
+                Class::FlakChild => copy_texture(asset_loader.texture(SpriteId::FlakChild), e.pos),
                 Class::None => ()
             }
         }
 
-        // health bar
-        let h = 4;
-        let mhp = Player::max_hp();
-        let w = self.burger.hp * 8;
-        let from_bot = h + 2;
-        let mw = mhp * 8;
-        let window_height = CENTER_Y * 2.00;
-        let hp_pos = Vector2(2.00, window_height - f64::from(from_bot));
-        draw::rec_top_left(hp_pos, mw, h, Color::from_rgba(155, 155, 155, 255));
-        draw::rec_top_left(
-            hp_pos,
-            w.max(0) as _,
-            h,
-            Color::from_rgba(255, 105, 105, 255)
-        );
-        // dash bar
-        let h = 2;
-        let w = self.burger.dash_charge * 8.00 * 8.00;
-        let dash_from_bot = from_bot + h;
-        let clr = if self.burger.can_dash() {
-            Color::from_rgba(255, 255, 255, 255)
-        } else {
-            Color::from_rgba(55, 155, 255, 255)
-        };
-        draw::rec_top_left(
-            Vector2(2.00, window_height - f64::from(dash_from_bot)),
-            w as _,
-            h,
-            clr
-        );
+        if self.frozen_time > 0 && settings.reduced_flash {
+            draw::vignette(BG_ON_DAMAGE, 0.60);
+        }
+    }
+    fn draw_trails(&self, asset_loader: &AssetLoader, hazards: &Hazards) {
+        for burger in &self.burgers {
+            for (pos, alpha) in burger.trail.ghosts() {
+                copy_tinted(
+                    asset_loader.texture(SpriteId::BurgerInvuln),
+                    pos,
+                    Color::new(1.00, 1.00, 1.00, alpha)
+                );
+            }
+        }
+        for e in &self.entities {
+            let Some(trail) = &e.trail else {
+                continue;
+            };
+            for (pos, alpha) in trail.ghosts() {
+                let tint = Color::new(1.00, 1.00, 1.00, alpha);
+                match e.class {
+                    Class::Slug => copy_tinted_with_rotation(
+                        asset_loader.texture(SpriteId::Slug),
+                        pos,
+                        e.vel.angle() + PI * 0.50,
+                        tint
+                    ),
+                    Class::Laser => {
+                        let (w, h) = laser_size(e.vel);
+                        draw::rec(
+                            pos,
+                            w,
+                            h,
+                            Color {
+                                a: alpha,
+                                ..hazards.laser
+                            }
+                        );
+                    }
+                    _ => ()
+                }
+            }
+        }
     }
     fn game_is_over(&self) -> bool {
-        !self.burger.is_alive()
+        !self.burgers.iter().any(Player::is_alive)
     }
-    fn reset() -> State {
+    fn reset(players: usize, preset: Preset, seed: u64) -> State {
+        let tuning = preset.tuning();
         let burger_start = CENTER + Vector2(0.00, 12.00);
+        let burgers = (0..players)
+            .map(|i| {
+                // spread players out side by side
+                let offset = (i as f64 - (players - 1) as f64 * 0.50) * 24.00;
+                Player::new(burger_start + Vector2(offset, 0.00), tuning.max_hp)
+            })
+            .collect();
 
         State {
             frozen_time: 0,
-            difficulty:  100.00,
-            score:       0,
-            burger:      Player::new(burger_start),
-            cheese:      Cheese::new(CENTER - Vector2(0.00, 12.00), burger_start),
-            particles:   Vec::new(),
-            counters:    Counters::default(),
-            entities:    Vec::new()
+            preset,
+            difficulty: tuning.start_difficulty,
+            director: None,
+            practice: None,
+            score: 0,
+            combo: Combo::default(),
+            stats: RunStats {
+                preset,
+                ..RunStats::new(seed)
+            },
+            rng: Rng::new(seed),
+            events: Vec::new(),
+            burgers,
+            cheese: Cheese::new(CENTER - Vector2(0.00, 12.00), burger_start),
+            particles: Vec::new(),
+            counters: Counters::default(),
+            entities: Vec::new()
         }
     }
 }
+
+/// Returns the colour of a warning at the given age. Warnings strobe between their two colours,
+/// or fade slowly between them with reduced flashing.
+fn warning_color(age: f64, hazards: &Hazards, reduced_flash: bool) -> Color {
+    let (a, b) = hazards.warning;
+    if reduced_flash {
+        let t = ((age * TAU / 40.00).sin() * 0.50 + 0.50) as f32;
+        lerp_color(a, b, t)
+    } else if age % 6.00 < 3.00 {
+        a
+    } else {
+        b
+    }
+}
+
+fn laser_size(vel: Vector2) -> (i32, i32) {
+    if vel.x().abs() > vel.y().abs() {
+        (36, 6)
+    } else {
+        (6, 36)
+    }
+}